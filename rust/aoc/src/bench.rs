@@ -0,0 +1,65 @@
+use aoc_common::{Answer, Solution};
+use serde::Serialize;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Summary of repeated timings of one phase, in nanoseconds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    pub min_ns: u128,
+    pub median_ns: u128,
+    pub max_ns: u128,
+}
+
+impl Stats {
+    /// Summarises `samples`, which must not be empty.
+    pub fn of(mut samples: Vec<Duration>) -> Stats {
+        samples.sort();
+        Stats {
+            min_ns: samples[0].as_nanos(),
+            median_ns: samples[samples.len() / 2].as_nanos(),
+            max_ns: samples[samples.len() - 1].as_nanos(),
+        }
+    }
+}
+
+/// Timings of parsing and of each part for one day's input.
+#[derive(Clone, Debug, Serialize)]
+pub struct Benchmark {
+    pub day: u32,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part_one: Stats,
+    pub part_two: Stats,
+}
+
+fn time<T>(iterations: usize, mut f: impl FnMut() -> T) -> Vec<Duration> {
+    (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect()
+}
+
+/// Times `S::parse` and both parts `iterations` times each; parts reuse the last parse.
+pub fn measure<S: Solution>(input: &str, iterations: usize) -> aoc_common::Result<Benchmark> {
+    let iterations = iterations.max(1);
+    let mut parse = Vec::with_capacity(iterations);
+    let mut solution = None;
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = S::parse(black_box(input))?;
+        parse.push(start.elapsed());
+        solution = Some(parsed);
+    }
+    let solution = solution.expect("at least one iteration");
+    Ok(Benchmark {
+        day: S::DAY,
+        iterations,
+        parse: Stats::of(parse),
+        part_one: Stats::of(time(iterations, || -> Answer { solution.part_one() })),
+        part_two: Stats::of(time(iterations, || -> Answer { solution.part_two() })),
+    })
+}
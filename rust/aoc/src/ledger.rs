@@ -0,0 +1,132 @@
+use aoc_common::Part;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::Path;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum LedgerError {
+    #[error("IO error: {0}")]
+    IOError(#[from] io::Error),
+    #[error("Malformed ledger: {0}")]
+    ParseError(#[from] toml::de::Error),
+    #[error("Cannot write ledger: {0}")]
+    SerializeError(#[from] toml::ser::Error),
+}
+
+/// A confirmed answer for one part of a day, tied to the input it was computed from.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub day: u32,
+    #[serde(with = "part_number")]
+    pub part: Part,
+    /// Name of the input, looked up through the inputs root like `aoc run` does, or an absolute
+    /// path.
+    pub input: String,
+    /// SHA-256 of the input contents, see [`input_hash`]; left out for answers noted down before
+    /// the input was to hand, which then verify against whatever input the name finds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    pub answer: String,
+}
+
+/// Result of solving an entry's input again.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Confirmed,
+    Mismatch(String),
+    InputChanged,
+    Failed(String),
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Confirmed => write!(f, "ok"),
+            Verdict::Mismatch(answer) => write!(f, "got {}", answer),
+            Verdict::InputChanged => write!(f, "input changed since the answer was recorded"),
+            Verdict::Failed(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Entry {
+    /// Solves `input`, the contents of the entry's input, and compares with the recorded answer.
+    pub fn verify(&self, input: &str) -> Verdict {
+        if self
+            .hash
+            .as_ref()
+            .is_some_and(|hash| *hash != input_hash(input))
+        {
+            return Verdict::InputChanged;
+        }
+        match crate::solve(self.day, self.part, input) {
+            Ok(answer) if answer.to_string() == self.answer => Verdict::Confirmed,
+            Ok(answer) => Verdict::Mismatch(answer.to_string()),
+            Err(e) => Verdict::Failed(e),
+        }
+    }
+}
+
+/// Confirmed answers, stored as TOML so the file can be reviewed and edited by hand.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Ledger {
+    #[serde(default, rename = "answer")]
+    pub entries: Vec<Entry>,
+}
+
+impl Ledger {
+    /// Loads the ledger at `path`, or an empty one if the file does not exist yet.
+    pub fn load(path: &Path) -> Result<Ledger, LedgerError> {
+        match fs::read_to_string(path) {
+            Ok(content) => Ok(toml::from_str(&content)?),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Ledger::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), LedgerError> {
+        fs::write(path, toml::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Adds `entry`, replacing any answer already recorded for the same day, part and input, be it
+    /// one with the same contents or one not yet tied to contents under the same name.
+    pub fn record(&mut self, entry: Entry) {
+        match self.entries.iter_mut().find(|e| {
+            (e.day, e.part) == (entry.day, entry.part)
+                && (e.hash == entry.hash || e.hash.is_none() && e.input == entry.input)
+        }) {
+            Some(existing) => *existing = entry,
+            None => self.entries.push(entry),
+        }
+        self.entries
+            .sort_by(|a, b| (a.day, a.part as u8, &a.input).cmp(&(b.day, b.part as u8, &b.input)));
+    }
+}
+
+pub fn input_hash(input: &str) -> String {
+    format!("{:x}", Sha256::digest(input.as_bytes()))
+}
+
+mod part_number {
+    use aoc_common::Part;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(part: &Part, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(match part {
+            Part::One => 1,
+            Part::Two => 2,
+        })
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Part, D::Error> {
+        u8::deserialize(deserializer)?
+            .to_string()
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
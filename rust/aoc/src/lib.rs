@@ -0,0 +1,51 @@
+use aoc_common::{Answer, Part, Solution};
+use bench::Benchmark;
+
+pub mod bench;
+pub mod ledger;
+
+/// Type-erased entry point into one day's [`Solution`].
+pub struct Day {
+    pub day: u32,
+    pub solve: fn(&str, Part) -> aoc_common::Result<Answer>,
+    pub bench: fn(&str, usize) -> aoc_common::Result<Benchmark>,
+}
+
+impl Day {
+    fn of<S: Solution>() -> Day {
+        Day {
+            day: S::DAY,
+            solve: S::solve,
+            bench: bench::measure::<S>,
+        }
+    }
+}
+
+/// Every day that has a solution in the workspace, in calendar order.
+pub fn days() -> Vec<Day> {
+    vec![
+        Day::of::<aoc2023_day4::Day4>(),
+        Day::of::<aoc2023_day6::Day6>(),
+        Day::of::<aoc2023_day7::Day7>(),
+        Day::of::<aoc2023_day8::Day8>(),
+        Day::of::<aoc2023_day9::Day9>(),
+        Day::of::<aoc2023_day10::Day10>(),
+        Day::of::<aoc2023_day11::Day11>(),
+        Day::of::<aoc2023_day12::Day12>(),
+        Day::of::<aoc2023_day15::Day15>(),
+        Day::of::<aoc2023_day16::Day16>(),
+        Day::of::<aoc2023_day17::Day17>(),
+        Day::of::<aoc2023_day18::Day18>(),
+        Day::of::<aoc2023_day19::Day19>(),
+    ]
+}
+
+pub fn day(day: u32) -> Option<Day> {
+    days().into_iter().find(|d| d.day == day)
+}
+
+/// Solves `part` of `day` for `input`, with errors spelled out for the command line.
+pub fn solve(day: u32, part: Part, input: &str) -> Result<Answer, String> {
+    let solution = self::day(day).ok_or_else(|| format!("No solution for day {}", day))?;
+    (solution.solve)(input, part).map_err(|e| format!("Day {} part {} failed: {}", day, part, e))
+}
@@ -0,0 +1,289 @@
+use aoc::bench::{Benchmark, Stats};
+use aoc::ledger::{input_hash, Entry, Ledger, Verdict};
+use aoc::solve;
+use aoc_common::inputs::{is_path, InputError, Inputs};
+use aoc_common::Part;
+use clap::{Parser, Subcommand};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Inputs root holding dayNN/input.txt and the like; overrides $AOC_INPUTS and aoc.toml
+    #[arg(long, global = true)]
+    inputs: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one part of a day for the given input file
+    Run {
+        #[arg(short, long)]
+        day: u32,
+        #[arg(short, long, default_value = "1")]
+        part: Part,
+        /// Input file or name under the inputs root, or '-' to read from stdin
+        #[arg(short, long, default_value = "input.txt")]
+        input: String,
+    },
+    /// Store a confirmed answer in the ledger, solving the input if no answer is given
+    Record {
+        #[arg(short, long)]
+        day: u32,
+        #[arg(short, long, default_value = "1")]
+        part: Part,
+        /// Input file or name under the inputs root; names are looked up again when verifying
+        #[arg(short, long, default_value = "input.txt")]
+        input: String,
+        /// Confirmed answer, e.g. as accepted by the website
+        #[arg(short, long)]
+        answer: Option<String>,
+        #[arg(short, long, default_value = "answers.toml")]
+        ledger: PathBuf,
+    },
+    /// Time parsing and both parts of one day, or of every day
+    Bench {
+        /// Day to benchmark; all days if omitted
+        #[arg(short, long)]
+        day: Option<u32>,
+        /// Input file or name under the inputs root; '{day}' is replaced with the day number
+        #[arg(short, long, default_value = "input.txt")]
+        input: String,
+        #[arg(short = 'n', long, default_value = "10")]
+        iterations: usize,
+        /// Print a JSON report instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+    /// Re-solve every ledger entry and report answers that no longer match
+    Verify {
+        #[arg(short, long, default_value = "answers.toml")]
+        ledger: PathBuf,
+    },
+}
+
+fn read_input(path: &str) -> io::Result<String> {
+    if path == "-" {
+        let mut input = String::new();
+        io::stdin().lock().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path)
+    }
+}
+
+/// Finds input `name` of `day`: as given for stdin and paths, else through [`Inputs`] with the
+/// day's crate directory as a fallback.
+fn locate(root: &Option<PathBuf>, day: u32, name: &str) -> Result<String, InputError> {
+    if name == "-" || is_path(name) {
+        return Ok(name.to_string());
+    }
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    Inputs::new(root.clone())
+        .map(|inputs| inputs.with_fallback(workspace.join(format!("aoc2023-day{}", day))))
+        .and_then(|inputs| inputs.find(day, name))
+        .map(|path| path.display().to_string())
+}
+
+/// Reads input `name` of `day`, or `None` if there is no such input, e.g. a puzzle input that is
+/// not checked in on this machine.
+fn load(root: &Option<PathBuf>, day: u32, name: &str) -> Result<Option<String>, String> {
+    let path = match locate(root, day, name) {
+        Ok(path) => path,
+        Err(InputError::NotFound { .. }) => return Ok(None),
+        Err(e) => return Err(e.to_string()),
+    };
+    match read_input(&path) {
+        Ok(input) => Ok(Some(input)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(format!("Cannot read {}: {}", path, e)),
+    }
+}
+
+fn run(day: u32, part: Part, path: &str) -> Result<(), String> {
+    let input = read_input(path).map_err(|e| format!("Cannot read {}: {}", path, e))?;
+    println!("{}", solve(day, part, &input)?);
+    Ok(())
+}
+
+fn record(
+    root: &Option<PathBuf>,
+    day: u32,
+    part: Part,
+    name: String,
+    answer: Option<String>,
+    ledger_path: PathBuf,
+) -> Result<(), String> {
+    if name == "-" {
+        return Err("Cannot record stdin, verify needs a file it can solve again".to_string());
+    }
+    let path = locate(root, day, &name).map_err(|e| e.to_string())?;
+    let input = read_input(&path).map_err(|e| format!("Cannot read {}: {}", path, e))?;
+    // names are looked up again when verifying, paths must not depend on where that happens
+    let name = if is_path(&name) {
+        let path =
+            fs::canonicalize(&path).map_err(|e| format!("Cannot resolve {}: {}", path, e))?;
+        path.display().to_string()
+    } else {
+        name
+    };
+    let answer = match answer {
+        Some(answer) => answer,
+        None => solve(day, part, &input)?.to_string(),
+    };
+    let mut ledger = Ledger::load(&ledger_path)
+        .map_err(|e| format!("Cannot load {}: {}", ledger_path.display(), e))?;
+    println!("Day {} part {}: {}", day, part, answer);
+    ledger.record(Entry {
+        day,
+        part,
+        hash: Some(input_hash(&input)),
+        input: name,
+        answer,
+    });
+    ledger
+        .save(&ledger_path)
+        .map_err(|e| format!("Cannot save {}: {}", ledger_path.display(), e))
+}
+
+fn verify(root: &Option<PathBuf>, ledger_path: PathBuf) -> Result<(), String> {
+    let ledger = Ledger::load(&ledger_path)
+        .map_err(|e| format!("Cannot load {}: {}", ledger_path.display(), e))?;
+    let mut failures = 0;
+    let mut skipped = 0;
+    for entry in &ledger.entries {
+        let label = format!("Day {} part {} ({})", entry.day, entry.part, entry.input);
+        let verdict = match load(root, entry.day, &entry.input) {
+            Ok(Some(input)) => entry.verify(&input),
+            Ok(None) => {
+                skipped += 1;
+                println!("{}: skipped, input not found", label);
+                continue;
+            }
+            Err(e) => Verdict::Failed(e),
+        };
+        match verdict {
+            Verdict::Confirmed => println!("{}: ok", label),
+            Verdict::Mismatch(answer) => {
+                failures += 1;
+                println!("{}: expected {}, got {}", label, entry.answer, answer);
+            }
+            verdict => {
+                failures += 1;
+                println!("{}: {}", label, verdict);
+            }
+        }
+    }
+    if skipped > 0 {
+        println!(
+            "{} of {} answers skipped, their input is missing",
+            skipped,
+            ledger.entries.len()
+        );
+    }
+    if failures == 0 {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} of {} answers failed",
+            failures,
+            ledger.entries.len()
+        ))
+    }
+}
+
+fn bench(
+    root: &Option<PathBuf>,
+    day: Option<u32>,
+    input: String,
+    iterations: usize,
+    json: bool,
+) -> Result<(), String> {
+    let days = match day {
+        Some(day) => vec![aoc::day(day).ok_or_else(|| format!("No solution for day {}", day))?],
+        None => aoc::days(),
+    };
+    let mut reports = Vec::new();
+    for solution in days {
+        let name = input.replace("{day}", &solution.day.to_string());
+        let input = if day.is_some() {
+            let path = locate(root, solution.day, &name).map_err(|e| e.to_string())?;
+            read_input(&path).map_err(|e| format!("Cannot read {}: {}", path, e))?
+        } else {
+            match load(root, solution.day, &name)? {
+                Some(input) => input,
+                None => {
+                    eprintln!("Skipping day {}, {} not found", solution.day, name);
+                    continue;
+                }
+            }
+        };
+        let report = (solution.bench)(&input, iterations)
+            .map_err(|e| format!("Day {} failed: {}", solution.day, e))?;
+        if !json {
+            print_benchmark(&report);
+        }
+        reports.push(report);
+    }
+    if json {
+        let report = serde_json::to_string_pretty(&reports).map_err(|e| e.to_string())?;
+        println!("{}", report);
+    }
+    Ok(())
+}
+
+fn print_benchmark(report: &Benchmark) {
+    let row = |phase: &str, stats: &Stats| {
+        let [min, median, max] = [stats.min_ns, stats.median_ns, stats.max_ns]
+            .map(|ns| format!("{:?}", Duration::from_nanos(ns as u64)));
+        println!(
+            "day {:>2} {:<8} min {:>12} median {:>12} max {:>12}",
+            report.day, phase, min, median, max
+        );
+    };
+    row("parse", &report.parse);
+    row("part 1", &report.part_one);
+    row("part 2", &report.part_two);
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run { day, part, input } => report(
+            locate(&cli.inputs, day, &input)
+                .map_err(|e| e.to_string())
+                .and_then(|path| run(day, part, &path)),
+        ),
+        Command::Record {
+            day,
+            part,
+            input,
+            answer,
+            ledger,
+        } => report(record(&cli.inputs, day, part, input, answer, ledger)),
+        Command::Bench {
+            day,
+            input,
+            iterations,
+            json,
+        } => report(bench(&cli.inputs, day, input, iterations, json)),
+        Command::Verify { ledger } => report(verify(&cli.inputs, ledger)),
+    }
+}
+
+fn report(result: Result<(), String>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
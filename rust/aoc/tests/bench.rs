@@ -0,0 +1,49 @@
+use aoc::bench::Stats;
+use pretty_assertions::assert_eq;
+use std::fs;
+use std::time::Duration;
+
+#[test]
+fn stats_summarise_unsorted_samples() {
+    let samples = [30, 10, 50, 20, 40].map(Duration::from_nanos).to_vec();
+    assert_eq!(
+        Stats::of(samples),
+        Stats {
+            min_ns: 10,
+            median_ns: 30,
+            max_ns: 50,
+        }
+    );
+}
+
+#[test]
+fn benchmark_reports_day_and_iterations() {
+    let day = aoc::day(9).unwrap();
+    let input = std::fs::read_to_string(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../aoc2023-day9/test.txt"
+    ))
+    .unwrap();
+    let report = (day.bench)(&input, 3).unwrap();
+    assert_eq!((report.day, report.iterations), (9, 3));
+}
+
+#[test]
+fn bench_without_day_skips_days_without_input() {
+    let dir = std::env::temp_dir().join(format!("aoc-bench-{}", std::process::id()));
+    fs::create_dir_all(dir.join("day09")).unwrap();
+    fs::write(dir.join("day09").join("only.txt"), "0 3 6 9\n").unwrap();
+
+    let benched = std::process::Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["bench", "-i", "only.txt", "-n", "1", "--json", "--inputs"])
+        .arg(&dir)
+        .output()
+        .unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(benched.status.success(), "{:?}", benched);
+    let reports: Vec<serde_json::Value> = serde_json::from_slice(&benched.stdout).unwrap();
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0]["day"], 9);
+    assert!(String::from_utf8_lossy(&benched.stderr).contains("Skipping day 4, only.txt not found"));
+}
@@ -0,0 +1,86 @@
+use aoc_common::Part;
+use pretty_assertions::assert_eq;
+use std::fs;
+use std::path::PathBuf;
+
+/// Day, example file checked in next to the day's crate, part and known answer.
+const EXAMPLES: &[(u32, &str, Part, u128)] = &[
+    (4, "test.txt", Part::One, 13),
+    (4, "test.txt", Part::Two, 30),
+    (6, "test.txt", Part::One, 288),
+    (6, "test.txt", Part::Two, 71503),
+    (7, "test.txt", Part::One, 6440),
+    (7, "test.txt", Part::Two, 5905),
+    (8, "test.txt", Part::One, 2),
+    (8, "test3.txt", Part::Two, 6),
+    (9, "test.txt", Part::One, 114),
+    (9, "test.txt", Part::Two, 2),
+    (10, "test.txt", Part::One, 8),
+    (10, "test.txt", Part::Two, 1),
+    (10, "test2.txt", Part::One, 23),
+    (10, "test2.txt", Part::Two, 4),
+    (10, "test3.txt", Part::One, 80),
+    (10, "test3.txt", Part::Two, 10),
+    (11, "test.txt", Part::One, 374),
+    (11, "test.txt", Part::Two, 82000210),
+    (12, "test.txt", Part::One, 21),
+    (12, "test.txt", Part::Two, 525152),
+    (15, "test.txt", Part::One, 1320),
+    (15, "test.txt", Part::Two, 145),
+    (16, "test.txt", Part::One, 46),
+    (16, "test.txt", Part::Two, 51),
+    (17, "test.txt", Part::One, 102),
+    (17, "test.txt", Part::Two, 94),
+    (18, "test.txt", Part::One, 62),
+    (18, "test.txt", Part::Two, 952408144115),
+    (19, "test.txt", Part::One, 19114),
+    (19, "test.txt", Part::Two, 167409079868000),
+];
+
+fn example_path(day: u32, file: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("aoc2023-day{}", day))
+        .join(file)
+}
+
+fn describe(day: u32, file: &str, part: Part, result: &str) -> String {
+    format!("day {:2} {:9} part {}: {}", day, file, part, result)
+}
+
+#[test]
+fn examples_match_known_answers() {
+    let mut expected = Vec::new();
+    let mut actual = Vec::new();
+
+    for &(day, file, part, answer) in EXAMPLES {
+        let solution = aoc::day(day).unwrap_or_else(|| panic!("No solution for day {}", day));
+        let path = example_path(day, file);
+        let input = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("Cannot read {}: {}", path.display(), e));
+
+        let result = match (solution.solve)(&input, part) {
+            Ok(result) => result.to_string(),
+            Err(e) => format!("error: {}", e),
+        };
+
+        expected.push(describe(day, file, part, &answer.to_string()));
+        actual.push(describe(day, file, part, &result));
+    }
+
+    assert_eq!(expected.join("\n"), actual.join("\n"));
+}
+
+#[test]
+fn every_day_has_examples_for_both_parts() {
+    for day in aoc::days() {
+        for part in [Part::One, Part::Two] {
+            assert!(
+                EXAMPLES.iter().any(|e| e.0 == day.day && e.2 == part),
+                "Day {} part {} has no example",
+                day.day,
+                part
+            );
+        }
+    }
+}
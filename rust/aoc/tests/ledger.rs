@@ -0,0 +1,153 @@
+use aoc::ledger::{input_hash, Entry, Ledger, Verdict};
+use aoc_common::Part;
+use pretty_assertions::assert_eq;
+use std::fs;
+
+fn entry(part: Part, input: &str, answer: &str) -> Entry {
+    Entry {
+        day: 9,
+        part,
+        input: "test.txt".to_string(),
+        hash: Some(input_hash(input)),
+        answer: answer.to_string(),
+    }
+}
+
+#[test]
+fn missing_ledger_is_empty() {
+    let path = std::env::temp_dir().join("aoc-ledger-does-not-exist.toml");
+    assert!(Ledger::load(&path).unwrap().entries.is_empty());
+}
+
+#[test]
+fn record_replaces_answer_for_same_input() {
+    let mut ledger = Ledger::default();
+    ledger.record(entry(Part::Two, "0 3 6", "-3"));
+    ledger.record(entry(Part::One, "0 3 6", "9"));
+    ledger.record(entry(Part::One, "0 3 6", "18"));
+    ledger.record(entry(Part::One, "1 3 6", "10"));
+
+    let answers: Vec<_> = ledger.entries.iter().map(|e| e.answer.as_str()).collect();
+    assert_eq!(answers, ["18", "10", "-3"]);
+}
+
+#[test]
+fn ledger_round_trips_through_toml() {
+    let path = std::env::temp_dir().join(format!("aoc-ledger-{}.toml", std::process::id()));
+    let mut ledger = Ledger::default();
+    ledger.record(entry(Part::One, "0 3 6", "18"));
+    ledger.record(entry(Part::Two, "0 3 6", "-3"));
+    ledger.save(&path).unwrap();
+
+    let loaded = Ledger::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.entries, ledger.entries);
+}
+
+#[test]
+fn record_pins_an_answer_noted_without_its_input() {
+    let mut ledger = Ledger::default();
+    ledger.record(Entry {
+        hash: None,
+        ..entry(Part::One, "0 3 6", "9")
+    });
+    ledger.record(entry(Part::One, "0 3 6", "9"));
+
+    assert_eq!(ledger.entries, [entry(Part::One, "0 3 6", "9")]);
+}
+
+#[test]
+fn verify_solves_the_input_again() {
+    assert_eq!(
+        entry(Part::One, "0 3 6", "9").verify("0 3 6"),
+        Verdict::Confirmed
+    );
+    assert_eq!(
+        entry(Part::Two, "0 3 6", "-3").verify("0 3 6"),
+        Verdict::Confirmed
+    );
+    assert_eq!(
+        entry(Part::One, "0 3 6", "12").verify("0 3 6"),
+        Verdict::Mismatch("9".to_string())
+    );
+    assert_eq!(
+        entry(Part::One, "0 3 6", "9").verify("1 3 6"),
+        Verdict::InputChanged
+    );
+    assert!(matches!(
+        entry(Part::One, "0 x 6", "9").verify("0 x 6"),
+        Verdict::Failed(_)
+    ));
+}
+
+#[test]
+fn verify_without_a_hash_takes_any_input() {
+    let unpinned = Entry {
+        hash: None,
+        ..entry(Part::One, "0 3 6", "10")
+    };
+    assert_eq!(unpinned.verify("1 3 6"), Verdict::Confirmed);
+    assert_eq!(unpinned.verify("0 3 6"), Verdict::Mismatch("9".to_string()));
+}
+
+#[test]
+fn verify_finds_recorded_paths_from_elsewhere() {
+    let dir = std::env::temp_dir().join(format!("aoc-verify-{}", std::process::id()));
+    fs::create_dir_all(dir.join("elsewhere")).unwrap();
+    fs::write(dir.join("input.txt"), "0 3 6\n").unwrap();
+    let aoc = |cwd: &std::path::Path, args: &[&str]| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .current_dir(cwd)
+            .args(args)
+            .output()
+            .unwrap()
+    };
+    let record = [
+        "record",
+        "-d",
+        "9",
+        "-i",
+        "./input.txt",
+        "-l",
+        "answers.toml",
+    ];
+
+    let from_stdin = aoc(
+        &dir,
+        &["record", "-d", "9", "-i", "-", "-l", "answers.toml"],
+    );
+    let recorded = aoc(&dir, &record);
+    let verified = aoc(&dir.join("elsewhere"), &["verify", "-l", "../answers.toml"]);
+    fs::write(dir.join("input.txt"), "1 3 6\n").unwrap();
+    let changed = aoc(&dir, &["verify", "-l", "answers.toml"]);
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(!from_stdin.status.success());
+    assert!(recorded.status.success(), "{:?}", recorded);
+    assert!(verified.status.success(), "{:?}", verified);
+    assert!(!changed.status.success());
+    assert!(String::from_utf8_lossy(&changed.stdout)
+        .ends_with(": input changed since the answer was recorded\n"));
+}
+
+#[test]
+fn verify_skips_entries_without_input() {
+    let dir = std::env::temp_dir().join(format!("aoc-skip-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        dir.join("answers.toml"),
+        "[[answer]]\nday = 9\npart = 1\ninput = \"./missing.txt\"\nanswer = \"9\"\n",
+    )
+    .unwrap();
+
+    let verified = std::process::Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .current_dir(&dir)
+        .args(["verify", "-l", "answers.toml"])
+        .output()
+        .unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(verified.status.success(), "{:?}", verified);
+    assert!(String::from_utf8_lossy(&verified.stdout)
+        .starts_with("Day 9 part 1 (./missing.txt): skipped, input not found\n"));
+}
@@ -0,0 +1,172 @@
+use aoc_common::{Answer, ParsingError, Position, Solution};
+use map2d::{Direction, Map, Vec2D};
+
+/// Sides of a tile that its pipe connects to.
+fn openings(c: char) -> &'static [Direction] {
+    match c {
+        '|' => &[Direction::North, Direction::South],
+        '-' => &[Direction::East, Direction::West],
+        'L' => &[Direction::North, Direction::East],
+        'J' => &[Direction::North, Direction::West],
+        '7' => &[Direction::South, Direction::West],
+        'F' => &[Direction::South, Direction::East],
+        _ => &[],
+    }
+}
+
+/// Tiles enclosed by the loop through `path`.
+fn find_area(map: &Map<char>, path: &[Vec2D]) -> i64 {
+    // draw the loop at three times the scale, so that the gap between two pipes running
+    // side by side becomes a corridor the outside can flood through
+    let (width, height) = (map.width * 3, map.height * 3);
+    let mut walls = Map::new(height, width, vec![false; width * height]);
+    let mut on_loop = vec![false; map.width * map.height];
+
+    for &pos in path {
+        on_loop[map.index_of(pos).unwrap()] = true;
+        let centre = pos * 3 + Vec2D { x: 1, y: 1 };
+        walls.set(centre, true);
+        for direction in openings(map[pos]) {
+            walls.set(centre + direction.to_vec2d(), true);
+        }
+    }
+
+    let mut outside = vec![false; width * height];
+    for pos in walls.fill_outside(|&wall| !wall) {
+        outside[walls.index_of(pos).unwrap()] = true;
+    }
+
+    let enclosed = (0..map.width * map.height)
+        .filter(|&index| !on_loop[index])
+        .filter(|&index| {
+            let pos = map.coords(index);
+            !outside[(pos.y as usize * 3 + 1) * width + pos.x as usize * 3 + 1]
+        })
+        .count();
+
+    enclosed as i64
+}
+
+fn get_direction(c: &char, prev: Direction) -> Option<Direction> {
+    match prev {
+        Direction::North => match c {
+            '|' => Some(prev),
+            'F' => Some(Direction::East),
+            '7' => Some(Direction::West),
+            _ => None,
+        },
+        Direction::South => match c {
+            '|' => Some(prev),
+            'L' => Some(Direction::East),
+            'J' => Some(Direction::West),
+            _ => None,
+        },
+        Direction::East => match c {
+            '-' => Some(prev),
+            'J' => Some(Direction::North),
+            '7' => Some(Direction::South),
+            _ => None,
+        },
+        Direction::West => match c {
+            '-' => Some(prev),
+            'F' => Some(Direction::South),
+            'L' => Some(Direction::North),
+            _ => None,
+        },
+    }
+}
+
+/// Error for the tile at `position`, which must lie on the map.
+fn invalid_tile(map: &Map<char>, position: Vec2D, reason: String) -> ParsingError {
+    ParsingError::InvalidCell {
+        position: Position {
+            line: position.y as usize + 1,
+            column: position.x as usize + 1,
+        },
+        cell: map[position],
+        reason,
+    }
+}
+
+/// Replaces the start tile by the pipe hidden under it and returns the tiles of the loop in
+/// order, beginning at the start.
+fn trace_loop(map: &mut Map<char>) -> aoc_common::Result<Vec<Vec2D>> {
+    let start = map.rows().flatten().position(|c| *c == 'S');
+    let start = map.coords(start.ok_or(ParsingError::Missing("start tile 'S'"))?);
+
+    let connections: Vec<Direction> = Direction::iter()
+        .filter(|&direction| {
+            map.get(start + direction.to_vec2d())
+                .is_some_and(|c| get_direction(c, direction).is_some())
+        })
+        .collect();
+    let symbol = "|-LJ7F"
+        .chars()
+        .find(|&c| openings(c).iter().all(|d| connections.contains(d)));
+    let symbol = match symbol {
+        Some(symbol) if connections.len() == 2 => symbol,
+        _ => {
+            let reason = format!("start connects to {} pipes, expected 2", connections.len());
+            return Err(invalid_tile(map, start, reason));
+        }
+    };
+    map.set(start, symbol);
+
+    let mut path = vec![start];
+    let mut position = start;
+    let mut heading = connections[0];
+    loop {
+        let next = position + heading.to_vec2d();
+        if next == start {
+            return Ok(path);
+        }
+        let Some(c) = map.get(next) else {
+            let reason = "pipe leads off the map".to_string();
+            return Err(invalid_tile(map, position, reason));
+        };
+        heading = match get_direction(c, heading) {
+            Some(heading) => heading,
+            None => {
+                let reason = "does not connect to the pipe before it".to_string();
+                return Err(invalid_tile(map, next, reason));
+            }
+        };
+        position = next;
+        path.push(position);
+    }
+}
+
+fn parse(input: &str) -> aoc_common::Result<(Map<char>, Vec<Vec2D>)> {
+    let mut map = Map::parse_with(input, |c| {
+        if "|-LJ7F.S".contains(c) {
+            Ok(c)
+        } else {
+            Err("expected a pipe tile")
+        }
+    })?;
+    let path = trace_loop(&mut map)?;
+    Ok((map, path))
+}
+
+pub struct Day10 {
+    map: Map<char>,
+    path: Vec<Vec2D>,
+}
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+
+    fn parse(input: &str) -> aoc_common::Result<Day10> {
+        let (map, path) = parse(input)?;
+        Ok(Day10 { map, path })
+    }
+
+    fn part_one(&self) -> Answer {
+        // the furthest tile is halfway round the loop
+        (self.path.len() as i64 / 2).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        find_area(&self.map, &self.path).into()
+    }
+}
@@ -0,0 +1,48 @@
+use aoc2023_day10::Day10;
+use aoc_common::{ParsingError, Position, Solution};
+
+fn invalid_tile(input: &str) -> (Position, char, String) {
+    match Day10::parse(input) {
+        Err(ParsingError::InvalidCell {
+            position,
+            cell,
+            reason,
+        }) => (position, cell, reason),
+        Err(e) => panic!("unexpected error {e}"),
+        Ok(_) => panic!("{input} parsed"),
+    }
+}
+
+#[test]
+fn start_needs_two_connecting_pipes() {
+    let (position, cell, reason) = invalid_tile("S..\n...\n");
+    assert_eq!(position, Position { line: 1, column: 1 });
+    assert_eq!(cell, 'S');
+    assert_eq!(reason, "start connects to 0 pipes, expected 2");
+
+    let (_, _, reason) = invalid_tile(".|.\n-S-\n.|.\n");
+    assert_eq!(reason, "start connects to 4 pipes, expected 2");
+
+    let (_, _, reason) = invalid_tile("...\n.S-\n...\n");
+    assert_eq!(reason, "start connects to 1 pipes, expected 2");
+}
+
+#[test]
+fn loop_must_close() {
+    let (position, cell, reason) = invalid_tile("S-7\n|.|\nL-.\n");
+    assert_eq!(position, Position { line: 3, column: 3 });
+    assert_eq!(cell, '.');
+    assert_eq!(reason, "does not connect to the pipe before it");
+
+    let (position, cell, reason) = invalid_tile("S--\n|.|\nL-J\n");
+    assert_eq!(position, Position { line: 1, column: 3 });
+    assert_eq!(cell, '-');
+    assert_eq!(reason, "pipe leads off the map");
+}
+
+#[test]
+fn closed_loop_parses() {
+    let day = Day10::parse("S-7\n|.|\nL-J\n").unwrap();
+    assert_eq!(day.part_one(), 4u64.into());
+    assert_eq!(day.part_two(), 1u64.into());
+}
@@ -0,0 +1,113 @@
+use aoc_common::{Answer, Solution};
+use itertools::iproduct;
+use map2d::{Map, Vec2D};
+
+struct Problem {
+    map: Map<char>,
+    galaxy_rows: Vec<i64>,
+    galaxy_cols: Vec<i64>,
+    multiplier: i64,
+}
+
+impl Problem {
+    pub fn new(map: Map<char>, multiplier: i64) -> Problem {
+        let galaxy_rows: Vec<i64> = map
+            .rows()
+            .map(|row| if row.contains(&'#') { 1 } else { 0 })
+            .collect();
+
+        let galaxy_cols: Vec<i64> = map
+            .columns()
+            .map(|mut column| {
+                column
+                    .find_map(|c| if *c == '#' { Some(1) } else { None })
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        Problem {
+            map,
+            galaxy_rows,
+            galaxy_cols,
+            multiplier,
+        }
+    }
+
+    fn get_empty_spaces(&self, a: i64, b: i64, by_columns: bool) -> i64 {
+        let start = a.min(b) as usize;
+        let end = a.max(b) as usize;
+        if by_columns {
+            self.galaxy_cols[start..end]
+                .iter()
+                .filter(|x| **x == 0i64)
+                .count() as i64
+        } else {
+            self.galaxy_rows[start..end]
+                .iter()
+                .filter(|x| **x == 0)
+                .count() as i64
+        }
+    }
+
+    pub fn distance(&self, a: &Vec2D, b: &Vec2D) -> i64 {
+        let empty = self.get_empty_spaces(a.x, b.x, true) + self.get_empty_spaces(a.y, b.y, false);
+
+        (*a - *b).manhattan() + empty * (self.multiplier - 1)
+    }
+
+    pub fn solve(&self) -> i64 {
+        let galaxies: Vec<Vec2D> = self
+            .map
+            .rows()
+            .flatten()
+            .enumerate()
+            .flat_map(|x| {
+                if *x.1 == '#' {
+                    Some(self.map.coords(x.0))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let product: i64 = iproduct!(&galaxies, &galaxies)
+            .map(|(a, b)| self.distance(a, b))
+            .sum();
+
+        product / 2
+    }
+}
+
+fn parse(input: &str) -> aoc_common::Result<Map<char>> {
+    Map::parse_with(input, |c| match c {
+        '.' | '#' => Ok(c),
+        _ => Err("expected '.' or '#'"),
+    })
+}
+
+fn process(map: &Map<char>, part_two: bool) -> i64 {
+    let multiplier = if part_two { 1000000 } else { 2 };
+
+    let problem = Problem::new(map.clone(), multiplier);
+    problem.solve()
+}
+
+pub struct Day11 {
+    map: Map<char>,
+}
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+
+    fn parse(input: &str) -> aoc_common::Result<Day11> {
+        Ok(Day11 { map: parse(input)? })
+    }
+
+    fn part_one(&self) -> Answer {
+        process(&self.map, false).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        process(&self.map, true).into()
+    }
+}
@@ -0,0 +1,154 @@
+use aoc_common::{parse_number, Answer, ParsingError, Solution};
+use std::iter::repeat_n;
+
+use ndarray::{Array2, ShapeBuilder};
+
+struct Problem {
+    parts: String,
+    sequences: Vec<u64>,
+}
+
+impl Problem {
+    fn solve(&self) -> u64 {
+        let mut p = Array2::<u64>::zeros((self.parts.len(), self.sequences.len() + 1).f());
+
+        // dbg!(&self.parts);
+        // dbg!(&self.sequences);
+
+        for i in 0..self.parts.len() {
+            p[[i, 0]] = 1;
+        }
+
+        for j in 1..=self.sequences.len() {
+            for i in 0..self.parts.len() {
+                let iu64 = i as u64;
+                let ju64 = j as u64;
+
+                let cur_len = self.sequences[j - 1] as usize;
+
+                let min_index: u64 = self.sequences.iter().take(j).sum::<u64>() + ju64 - 1 - 1;
+                if iu64 < min_index {
+                    continue;
+                }
+
+                let mut can_place = true;
+
+                if i >= cur_len && self.parts.chars().nth(i - cur_len).unwrap() == '#' {
+                    can_place = false;
+                }
+
+                if self.parts.chars().nth(i + 1).unwrap_or('.') == '#' {
+                    can_place = false;
+                }
+
+                if j == 1 && i >= cur_len && self.parts[0..=i - cur_len].contains('#') {
+                    can_place = false; // cannot leave #'s left of first sequence
+                }
+
+                if j == self.sequences.len() && self.parts[(i + 1)..].contains('#') {
+                    can_place = false;  // cannot have #'s right of last sequence
+                }
+
+                if self.parts[((i + 1) - cur_len)..=i].contains('.') {
+                    can_place = false;
+                }
+
+                let next: u64 = if can_place {
+                    if i < cur_len + 1 {
+                        1
+                    } else {
+                        p[[i - cur_len - 1, j - 1]]
+                    }
+                } else {
+                    0
+                };
+
+                let prev = if i == 0 { 0 } else { p[[i - 1, j]] };
+
+                p[[i, j]] = if self.parts.chars().nth(i).unwrap() == '#' {
+                    next
+                } else {
+                    next + prev
+                };
+            }
+        }
+
+
+        // for i in 0..self.parts.len() {
+        //     print!("\n{}: ", &i);
+        //     for j in 0..self.sequences.len() + 1 {
+        //         print!("{:2} |", p[[i, j]]);
+        //     }
+        // }
+        // println!("\n\n");
+
+        p[[self.parts.len() - 1, self.sequences.len()]]
+    }
+}
+
+fn parse(input: &str, part_two: bool) -> aoc_common::Result<Vec<Problem>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let Some((parts, sequences)) = line.split_once(' ') else {
+                return Err(ParsingError::unexpected(index, line, line, "a condition record"));
+            };
+            if parts.is_empty() {
+                return Err(ParsingError::unexpected(index, line, parts, "a row of springs"));
+            }
+            if let Some(spring) = parts.matches(|c| !"?#.".contains(c)).next() {
+                return Err(ParsingError::unexpected(index, line, spring, "'?', '#' or '.'"));
+            }
+            let sequences = sequences
+                .split(',')
+                .map(|x| match parse_number::<u64>(index, line, x)? {
+                    0 => Err(ParsingError::unexpected(index, line, x, "a group of at least one")),
+                    group => Ok(group),
+                })
+                .collect::<aoc_common::Result<Vec<_>>>()?;
+
+            Ok(if part_two {
+                Problem {
+                    parts: repeat_n(parts, 5).collect::<Vec<_>>().join("?"),
+                    sequences: sequences.repeat(5),
+                }
+            } else {
+                Problem {
+                    parts: parts.to_string(),
+                    sequences,
+                }
+            })
+        })
+        .collect()
+}
+
+fn process(problems: &[Problem]) -> i64 {
+    let solutions: Vec<_> = problems.iter().map(|p| p.solve()).collect();
+    solutions.iter().sum::<u64>() as i64
+}
+
+pub struct Day12 {
+    problems: Vec<Problem>,
+    /// Records unfolded five times for part two
+    unfolded: Vec<Problem>,
+}
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+
+    fn parse(input: &str) -> aoc_common::Result<Day12> {
+        Ok(Day12 {
+            problems: parse(input, false)?,
+            unfolded: parse(input, true)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        process(&self.problems).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        process(&self.unfolded).into()
+    }
+}
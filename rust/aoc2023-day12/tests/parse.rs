@@ -0,0 +1,21 @@
+use aoc2023_day12::Day12;
+use aoc_common::{ParsingError, Solution};
+
+fn expected(input: &str) -> &'static str {
+    match Day12::parse(input) {
+        Err(ParsingError::UnexpectedToken { expected, .. }) => expected,
+        Err(e) => panic!("unexpected error {e}"),
+        Ok(_) => panic!("{input:?} parsed"),
+    }
+}
+
+#[test]
+fn records_need_springs() {
+    assert_eq!(expected(" 1\n"), "a row of springs");
+}
+
+#[test]
+fn groups_are_at_least_one_spring_long() {
+    assert_eq!(expected("#.# 0\n"), "a group of at least one");
+    assert_eq!(expected("#.# 1,0,1\n"), "a group of at least one");
+}
@@ -0,0 +1,22 @@
+use aoc2023_day15::Day15;
+use aoc_common::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const SEED: u64 = 2023;
+
+fn bench(c: &mut Criterion) {
+    let input = aoc_inputgen::day15(SEED, 100_000);
+    let day = Day15::parse(&input).unwrap();
+
+    let mut group = c.benchmark_group("day15 100k steps");
+    group.sample_size(10);
+    group.bench_function("parse", |b| {
+        b.iter(|| Day15::parse(black_box(&input)).unwrap())
+    });
+    group.bench_function("part one", |b| b.iter(|| day.part_one()));
+    group.bench_function("part two", |b| b.iter(|| day.part_two()));
+    group.finish();
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,120 @@
+use aoc_common::{parse_number, Answer, ParsingError, Solution};
+
+#[derive(Debug, Clone)]
+struct Lens {
+    power: i64,
+    label: String,
+}
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Insert(String, i64),
+    Remove(String),
+}
+
+fn parse(input: &str) -> aoc_common::Result<(Vec<String>, Vec<Operation>)> {
+    let mut words = Vec::new();
+    let mut operations = Vec::new();
+
+    for (index, line) in input.lines().enumerate() {
+        for word in line.trim().split(',').filter(|word| !word.is_empty()) {
+            let operation = if let Some((label, power)) = word.split_once('=') {
+                Operation::Insert(label.to_string(), parse_number(index, line, power)?)
+            } else if let Some(label) = word.strip_suffix('-') {
+                Operation::Remove(label.to_string())
+            } else {
+                return Err(ParsingError::unexpected(
+                    index,
+                    line,
+                    word,
+                    "'-' or '=' step",
+                ));
+            };
+            words.push(word.to_string());
+            operations.push(operation);
+        }
+    }
+
+    Ok((words, operations))
+}
+
+fn hash(word: &str) -> i64 {
+    let mut value: i64 = 0;
+
+    for character in word.bytes() {
+        value += character as i64;
+        value *= 17;
+        value %= 256;
+    }
+    value
+}
+
+fn process(words: &[String], operations: &[Operation], part_two: bool) -> i64 {
+    if !part_two {
+        let hashes: Vec<_> = words.iter().map(|word| hash(word)).collect();
+        hashes.iter().sum()
+    } else {
+        let mut boxes: Vec<Vec<Lens>> = Vec::new();
+        for _i in 0..256 {
+            boxes.push(Vec::new());
+        }
+
+        for operation in operations {
+            match operation {
+                Operation::Insert(label, power) => {
+                    let hash = hash(label);
+                    let power = *power;
+
+                    let onebox = &mut boxes[hash as usize];
+                    match onebox.iter_mut().find(|lens| lens.label == *label) {
+                        Some(ref mut lens) => {
+                            lens.power = power;
+                        }
+                        None => {
+                            onebox.push(Lens {
+                                power,
+                                label: label.clone(),
+                            });
+                        }
+                    }
+                }
+                Operation::Remove(label) => {
+                    let hash = hash(label);
+                    let onebox = &mut boxes[hash as usize];
+                    onebox.retain(|lens| lens.label != *label);
+                }
+            }
+        }
+
+        let mut power: i64 = 0;
+        for (i, onebox) in boxes.iter().enumerate() {
+            for (k, lens) in onebox.iter().enumerate() {
+                power += ((i + 1) as i64) * ((k + 1) as i64) * lens.power;
+            }
+        }
+
+        power
+    }
+}
+
+pub struct Day15 {
+    words: Vec<String>,
+    operations: Vec<Operation>,
+}
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+
+    fn parse(input: &str) -> aoc_common::Result<Day15> {
+        let (words, operations) = parse(input)?;
+        Ok(Day15 { words, operations })
+    }
+
+    fn part_one(&self) -> Answer {
+        process(&self.words, &self.operations, false).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        process(&self.words, &self.operations, true).into()
+    }
+}
@@ -0,0 +1,180 @@
+use aoc_common::{Answer, Solution};
+use map2d::render::{Overlay, Renderer, Rgb};
+use map2d::{Direction, Map, Vec2D};
+use std::collections::HashMap;
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone)]
+struct Step {
+    direction: Direction,
+    position: Vec2D,
+}
+
+fn parse(input: &str) -> aoc_common::Result<Map<char>> {
+    Map::parse_with(input, |c| match c {
+        '.' | '/' | '\\' | '-' | '|' => Ok(c),
+        _ => Err("expected empty space, a mirror or a splitter"),
+    })
+}
+
+fn redirect(element: char, direction: Direction) -> Vec<Direction> {
+    match element {
+        '/' => match direction {
+            Direction::North | Direction::South => vec![direction.turn_right()],
+            Direction::East | Direction::West => vec![direction.turn_left()],
+        },
+        '\\' => match direction {
+            Direction::North | Direction::South => vec![direction.turn_left()],
+            Direction::East | Direction::West => vec![direction.turn_right()],
+        },
+        '-' if !direction.is_horizontal() => vec![Direction::East, Direction::West],
+        '|' if direction.is_horizontal() => vec![Direction::North, Direction::South],
+        _ => vec![direction],
+    }
+}
+
+fn process(map: &Map<char>, part_two: bool) -> i64 {
+    if !part_two {
+        let start = Vec2D { x: 0, y: 0 };
+        let start_direction = Direction::East;
+        count_energized(map, start, start_direction)
+    } else {
+        let mut max_count = 0;
+        for x in 0..map.width {
+            let start = Vec2D { x: x as i64, y: 0 };
+            let c = count_energized(map, start, Direction::South);
+            if c > max_count {
+                max_count = c;
+            }
+
+            let start = Vec2D {
+                x: x as i64,
+                y: (map.height - 1) as i64,
+            };
+            let c = count_energized(map, start, Direction::North);
+            if c > max_count {
+                max_count = c;
+            }
+        }
+        for y in 0..map.height {
+            let start = Vec2D { x: 0, y: y as i64 };
+            let c = count_energized(map, start, Direction::East);
+            if c > max_count {
+                max_count = c;
+            }
+
+            let start = Vec2D {
+                x: (map.width - 1) as i64,
+                y: y as i64,
+            };
+            let c = count_energized(map, start, Direction::West);
+            if c > max_count {
+                max_count = c;
+            }
+        }
+
+        max_count
+    }
+}
+
+fn energize(map: &Map<char>, start: Vec2D, start_direction: Direction) -> Map<bool> {
+    let mut cache = Direction::iter()
+        .map(|d| {
+            (
+                d,
+                Map::new(map.height, map.width, vec![false; map.height * map.width]),
+            )
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut queue: VecDeque<Step> = VecDeque::new();
+    queue.push_back(Step {
+        direction: start_direction,
+        position: start,
+    });
+
+    while !queue.is_empty() {
+        let cs = queue.pop_front().unwrap();
+
+        // check if already "visited"
+        if let Some(visited) = cache.get(&cs.direction).unwrap().get(cs.position) {
+            if *visited {
+                // position was cached
+                continue;
+            }
+        } else {
+            // position outside of map
+            continue;
+        }
+
+        cache.get_mut(&cs.direction).unwrap().set(cs.position, true);
+
+        let element = map.get(cs.position).unwrap();
+
+        redirect(*element, cs.direction)
+            .into_iter()
+            .for_each(|d: Direction| {
+                queue.push_back(Step {
+                    direction: d,
+                    position: cs.position + d.to_vec2d(),
+                });
+            });
+    }
+
+    // a tile is energized if a beam crossed it in any direction
+    let mut energized = Map::new(map.height, map.width, vec![false; map.height * map.width]);
+    for cachemap in cache.values() {
+        for (index, &visited) in cachemap.rows().flatten().enumerate() {
+            if visited {
+                energized.set(map.coords(index), true);
+            }
+        }
+    }
+
+    energized
+}
+
+fn count_energized(map: &Map<char>, start: Vec2D, start_direction: Direction) -> i64 {
+    energize(map, start, start_direction)
+        .rows()
+        .flatten()
+        .filter(|&&energized| energized)
+        .count() as i64
+}
+
+pub struct Day16 {
+    map: Map<char>,
+}
+
+impl Day16 {
+    /// Tiles the part one beam, entering at the top-left heading east, passes through.
+    pub fn energized(&self) -> Map<bool> {
+        energize(&self.map, Vec2D { x: 0, y: 0 }, Direction::East)
+    }
+
+    /// The contraption with energized tiles highlighted, as in the puzzle statement.
+    pub fn renderer(&self) -> Renderer<'_, char> {
+        let energized = self.energized();
+        let positions: Vec<Vec2D> = (0..self.map.width * self.map.height)
+            .map(|index| self.map.coords(index))
+            .filter(|&position| energized[position])
+            .collect();
+        Renderer::new(&self.map, |&c| c).with_overlay(Overlay::new(positions, Rgb::YELLOW))
+    }
+}
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+
+    fn parse(input: &str) -> aoc_common::Result<Day16> {
+        Ok(Day16 { map: parse(input)? })
+    }
+
+    fn part_one(&self) -> Answer {
+        process(&self.map, false).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        process(&self.map, true).into()
+    }
+}
@@ -0,0 +1,13 @@
+use aoc2023_day16::Day16;
+use aoc_common::{Answer, Solution};
+
+#[test]
+fn beams_enter_from_every_edge_of_non_square_grids() {
+    // both are best entered from the right of the top row, with the splitter sending the beam
+    // down the first column
+    let wide = Day16::parse("|....\n./...\n").unwrap();
+    assert_eq!(wide.part_two(), Answer::from(6u64));
+
+    let tall = Day16::parse("|.\n./\n..\n..\n..\n").unwrap();
+    assert_eq!(tall.part_two(), Answer::from(6u64));
+}
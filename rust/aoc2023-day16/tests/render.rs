@@ -0,0 +1,43 @@
+use aoc2023_day16::Day16;
+use aoc_common::Solution;
+
+const ENERGIZED: &str = "\
+######....
+.#...#....
+.#...#####
+.#...##...
+.#...##...
+.#...##...
+.#..####..
+########..
+.#######..
+.#...#.#..
+";
+
+fn example() -> Day16 {
+    Day16::parse_file(concat!(env!("CARGO_MANIFEST_DIR"), "/test.txt")).unwrap()
+}
+
+#[test]
+fn energized_tiles_match_the_puzzle() {
+    let energized = example().energized();
+    let text: String = energized
+        .rows()
+        .map(|row| {
+            row.iter()
+                .map(|&e| if e { '#' } else { '.' })
+                .collect::<String>()
+                + "\n"
+        })
+        .collect();
+    assert_eq!(text, ENERGIZED);
+}
+
+#[test]
+fn renderer_keeps_the_contraption_glyphs() {
+    let day = example();
+    let mut out = Vec::new();
+    day.renderer().write_ascii(&mut out).unwrap();
+    let input = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/test.txt")).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), input);
+}
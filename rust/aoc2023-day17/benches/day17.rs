@@ -0,0 +1,29 @@
+use aoc2023_day17::Day17;
+use aoc_common::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion, SamplingMode};
+use std::time::Duration;
+
+const SEED: u64 = 2023;
+
+fn bench(c: &mut Criterion) {
+    let input = aoc_inputgen::day17(SEED, 1000, 1000);
+    let day = Day17::parse(&input).unwrap();
+
+    let mut group = c.benchmark_group("day17 1000x1000");
+    group.sample_size(10);
+    group.bench_function("parse", |b| {
+        b.iter(|| Day17::parse(black_box(&input)).unwrap())
+    });
+    // Each part takes tens of seconds at this size (about 15 s for part one and 30 s for part
+    // two in release), so the measurement time is set to ten runs: one iteration per sample,
+    // and a few minutes per part to collect the ten samples.
+    group.sampling_mode(SamplingMode::Flat);
+    group.measurement_time(Duration::from_secs(150));
+    group.bench_function("part one", |b| b.iter(|| day.part_one()));
+    group.measurement_time(Duration::from_secs(300));
+    group.bench_function("part two", |b| b.iter(|| day.part_two()));
+    group.finish();
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
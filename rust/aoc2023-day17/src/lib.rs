@@ -0,0 +1,189 @@
+use aoc_common::{Answer, Part, Solution};
+use map2d::search;
+use map2d::{Direction, Map, Turn, Vec2D};
+
+/// Position after a straight run. Every run ends in a turn, so how long the run was does not
+/// matter for what comes next.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+struct Vertex {
+    position: Vec2D,
+    direction: Option<Direction>,
+}
+
+/// The way the crucible went, from the cell after the start to the target.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Route {
+    pub heat_loss: i64,
+    /// Every cell entered, with the direction the crucible moved to enter it.
+    pub steps: Vec<(Vec2D, Direction)>,
+}
+
+impl Route {
+    /// The turns between consecutive straight runs.
+    pub fn turns(&self) -> Vec<Turn> {
+        self.steps
+            .windows(2)
+            .map(|w| w[0].1.turn_to(w[1].1))
+            .filter(|&turn| turn != Turn::Straight)
+            .collect()
+    }
+}
+
+fn arrow(direction: Direction) -> char {
+    match direction {
+        Direction::North => '^',
+        Direction::East => '>',
+        Direction::South => 'v',
+        Direction::West => '<',
+    }
+}
+
+fn parse(input: &str) -> aoc_common::Result<Map<u8>> {
+    Map::parse_with(input, |c| {
+        c.to_digit(10)
+            .map(|d| d as u8)
+            .ok_or("expected a heat loss digit")
+    })
+}
+
+/// Directions the crucible may leave `vertex` in. Away from the start it has to turn; at the
+/// start it may go anywhere but back against `heading`, or anywhere at all without one.
+fn get_next(vertex: &Vertex, heading: Option<Direction>) -> impl Iterator<Item = Direction> {
+    let current = vertex.direction;
+
+    Direction::iter().filter(move |&dir| match (current, heading) {
+        (Some(current), _) => current.is_perpendicular(dir),
+        (None, Some(heading)) => dir != heading.reverse(),
+        (None, None) => true,
+    })
+}
+
+fn find_route(
+    map: &Map<u8>,
+    start: Vec2D,
+    target: Vec2D,
+    heading: Option<Direction>,
+    (min_steps, max_steps): (i64, i64),
+) -> Option<Route> {
+    map.get(start)?;
+    map.get(target)?;
+
+    let start = Vertex {
+        position: start,
+        direction: None,
+    };
+
+    let successors = |vertex: &Vertex| {
+        let mut nexts = Vec::new();
+        for dir in get_next(vertex, heading) {
+            let mut path_heat = 0;
+
+            for step in 1..=max_steps {
+                let pos = vertex.position + dir.to_vec2d() * step;
+                let Some(heat) = map.get(pos) else {
+                    break;
+                };
+                path_heat += *heat as i64;
+                if step >= min_steps {
+                    let next = Vertex {
+                        position: pos,
+                        direction: Some(dir),
+                    };
+                    nexts.push((next, path_heat));
+                }
+            }
+        }
+        nexts
+    };
+
+    let (heat_loss, vertices) =
+        search::dijkstra(start, successors, |vertex| vertex.position == target)?;
+
+    // expand the straight runs between vertices into single steps
+    let mut steps = Vec::new();
+    for run in vertices.windows(2) {
+        let dir = run[1].direction?;
+        let mut pos = run[0].position;
+        while pos != run[1].position {
+            pos += dir.to_vec2d();
+            steps.push((pos, dir));
+        }
+    }
+
+    Some(Route { heat_loss, steps })
+}
+
+pub struct Day17 {
+    map: Map<u8>,
+}
+
+impl Day17 {
+    fn limits(part: Part) -> (i64, i64) {
+        match part {
+            Part::One => (1, 3),
+            Part::Two => (4, 10),
+        }
+    }
+
+    /// The least heat loss route for the crucible of `part` from the top-left corner to the
+    /// bottom-right one, if there is any.
+    pub fn route(&self, part: Part) -> Option<Route> {
+        let corner = Vec2D {
+            x: self.map.width as i64 - 1,
+            y: self.map.height as i64 - 1,
+        };
+        self.route_between(part, Vec2D { x: 0, y: 0 }, corner, None)
+    }
+
+    /// The least heat loss route from `start` to `target`, setting off facing `heading` if given.
+    ///
+    /// `None` if either position is off the map or the crucible cannot end a run on `target`.
+    pub fn route_between(
+        &self,
+        part: Part,
+        start: Vec2D,
+        target: Vec2D,
+        heading: Option<Direction>,
+    ) -> Option<Route> {
+        find_route(&self.map, start, target, heading, Day17::limits(part))
+    }
+
+    fn heat_loss(&self, part: Part) -> Answer {
+        match self.route(part) {
+            Some(route) => route.heat_loss.into(),
+            None => "no route".into(),
+        }
+    }
+
+    /// The heat loss grid with the route drawn over it as in the puzzle statement.
+    pub fn render(&self, route: &Route) -> String {
+        let mut cells: Vec<Vec<char>> = self
+            .map
+            .rows()
+            .map(|row| row.iter().map(|&heat| (b'0' + heat) as char).collect())
+            .collect();
+        for &(pos, dir) in &route.steps {
+            cells[pos.y as usize][pos.x as usize] = arrow(dir);
+        }
+        cells
+            .into_iter()
+            .map(|row| row.into_iter().collect::<String>() + "\n")
+            .collect()
+    }
+}
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+
+    fn parse(input: &str) -> aoc_common::Result<Day17> {
+        Ok(Day17 { map: parse(input)? })
+    }
+
+    fn part_one(&self) -> Answer {
+        self.heat_loss(Part::One)
+    }
+
+    fn part_two(&self) -> Answer {
+        self.heat_loss(Part::Two)
+    }
+}
@@ -0,0 +1,122 @@
+use aoc2023_day17::Day17;
+use aoc_common::{Answer, Part, Solution};
+use map2d::{Direction, Turn, Vec2D};
+
+const EXAMPLE_ROUTE: &str = "\
+2>>34^>>>1323
+32v>>>35v5623
+32552456v>>54
+3446585845v52
+4546657867v>6
+14385987984v4
+44578769877v6
+36378779796v>
+465496798688v
+456467998645v
+12246868655<v
+25465488877v5
+43226746555v>
+";
+
+fn example() -> (Day17, String) {
+    let input = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/test.txt")).unwrap();
+    (Day17::parse(&input).unwrap(), input)
+}
+
+#[test]
+fn route_adds_up_to_the_heat_loss() {
+    let (day, input) = example();
+    let grid: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+    for (part, expected) in [(Part::One, 102), (Part::Two, 94)] {
+        let route = day.route(part).unwrap();
+        assert_eq!(route.heat_loss, expected);
+        let total: i64 = route
+            .steps
+            .iter()
+            .map(|(pos, _)| (grid[pos.y as usize][pos.x as usize] - b'0') as i64)
+            .sum();
+        assert_eq!(total, route.heat_loss);
+        let (end, _) = route.steps.last().unwrap();
+        assert_eq!((end.x, end.y), (12, 12));
+    }
+}
+
+#[test]
+fn runs_respect_the_crucible_limits() {
+    let (day, _) = example();
+    for (part, min, max) in [(Part::One, 1, 3), (Part::Two, 4, 10)] {
+        let route = day.route(part).unwrap();
+        let mut runs = vec![1];
+        for w in route.steps.windows(2) {
+            assert_ne!(w[0].1.turn_to(w[1].1), Turn::Back);
+            if w[0].1 == w[1].1 {
+                *runs.last_mut().unwrap() += 1;
+            } else {
+                runs.push(1);
+            }
+        }
+        assert_eq!(runs.len(), route.turns().len() + 1);
+        assert!(runs.iter().all(|run| (min..=max).contains(run)), "{runs:?}");
+    }
+}
+
+#[test]
+fn render_overlays_arrows_like_the_puzzle() {
+    let (day, _) = example();
+    let route = day.route(Part::One).unwrap();
+    assert_eq!(day.render(&route), EXAMPLE_ROUTE);
+}
+
+#[test]
+fn unreachable_target_has_no_route() {
+    // the ultra crucible needs four blocks before it may stop
+    let day = Day17::parse("111\n").unwrap();
+    assert!(day.route(Part::Two).is_none());
+    assert_eq!(day.part_two(), Answer::from("no route"));
+    assert_eq!(day.part_one(), Answer::from(2));
+
+    let (day, _) = example();
+    let outside = Vec2D { x: 13, y: 0 };
+    assert!(day
+        .route_between(Part::One, Vec2D { x: 0, y: 0 }, outside, None)
+        .is_none());
+}
+
+#[test]
+fn routes_run_between_any_two_cells() {
+    let (day, input) = example();
+    let corner = Vec2D { x: 12, y: 12 };
+    let origin = Vec2D { x: 0, y: 0 };
+    let heat = |p: Vec2D| {
+        (input.lines().nth(p.y as usize).unwrap().as_bytes()[p.x as usize] - b'0') as i64
+    };
+
+    // any route walked backwards is just as valid, paying for the old start instead of the target
+    let forward = day.route(Part::One).unwrap();
+    let backward = day.route_between(Part::One, corner, origin, None).unwrap();
+    assert_eq!(
+        backward.heat_loss,
+        forward.heat_loss - heat(corner) + heat(origin)
+    );
+    assert_eq!(backward.steps.last().unwrap().0, origin);
+
+    let stay = day.route_between(Part::One, corner, corner, None).unwrap();
+    assert_eq!(stay.heat_loss, 0);
+    assert!(stay.steps.is_empty());
+}
+
+#[test]
+fn start_heading_rules_out_reversing() {
+    let (day, _) = example();
+    let origin = Vec2D { x: 0, y: 0 };
+    let corner = Vec2D { x: 12, y: 12 };
+    for (heading, first) in [
+        (Direction::West, Direction::South),
+        (Direction::North, Direction::East),
+    ] {
+        let route = day
+            .route_between(Part::One, origin, corner, Some(heading))
+            .unwrap();
+        assert_eq!(route.steps[0].1, first);
+    }
+}
@@ -0,0 +1,22 @@
+use aoc2023_day18::Day18;
+use aoc_common::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const SEED: u64 = 2023;
+
+fn bench(c: &mut Criterion) {
+    let input = aoc_inputgen::day18(SEED, 10_000);
+    let day = Day18::parse(&input).unwrap();
+
+    let mut group = c.benchmark_group("day18 20k instructions");
+    group.sample_size(10);
+    group.bench_function("parse", |b| {
+        b.iter(|| Day18::parse(black_box(&input)).unwrap())
+    });
+    group.bench_function("part one", |b| b.iter(|| day.part_one()));
+    group.bench_function("part two", |b| b.iter(|| day.part_two()));
+    group.finish();
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
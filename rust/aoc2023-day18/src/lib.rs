@@ -0,0 +1,100 @@
+use aoc_common::{parse_number, Answer, ParsingError, Position, Solution};
+use map2d::{Direction, ParseDirectionError, Polygon, Vec2D};
+
+struct Instruction {
+    direction: Direction,
+    steps: i64,
+}
+
+/// Reads `token` as a single character in the notation `parse` accepts.
+fn direction(
+    index: usize,
+    line: &str,
+    token: &str,
+    parse: fn(char) -> Result<Direction, ParseDirectionError>,
+) -> aoc_common::Result<Direction> {
+    let mut chars = token.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => parse(c).ok(),
+        _ => None,
+    }
+    .ok_or_else(|| ParsingError::unknown_direction(index, line, token))
+}
+
+fn parse(input: &str, part_two: bool) -> aoc_common::Result<Polygon> {
+    let instr: Vec<Instruction> = input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let parts: Vec<&str> = line.split(' ').collect();
+            let [letter, steps, color] = parts[..] else {
+                return Err(ParsingError::unexpected(
+                    index,
+                    line,
+                    line,
+                    "a dig instruction",
+                ));
+            };
+
+            if !part_two {
+                Ok(Instruction {
+                    direction: direction(index, line, letter, Direction::from_letter)?,
+                    steps: parse_number(index, line, steps)?,
+                })
+            } else {
+                let hex = color
+                    .strip_prefix("(#")
+                    .and_then(|c| c.strip_suffix(')'))
+                    .filter(|h| h.len() == 6 && h.is_ascii())
+                    .ok_or_else(|| {
+                        ParsingError::unexpected(index, line, color, "a colour like (#70c710)")
+                    })?;
+                let steps = i64::from_str_radix(&hex[..5], 16).map_err(|source| {
+                    ParsingError::InvalidNumber {
+                        position: Position::locate(index, line, &hex[..5]),
+                        token: hex[..5].to_string(),
+                        source,
+                    }
+                })?;
+                Ok(Instruction {
+                    direction: direction(index, line, &hex[5..], Direction::from_digit)?,
+                    steps,
+                })
+            }
+        })
+        .collect::<aoc_common::Result<_>>()?;
+
+    let Some((last, line)) = input.lines().enumerate().last() else {
+        return Err(ParsingError::Missing("dig plan"));
+    };
+    Polygon::trace(
+        Vec2D { x: 0, y: 0 },
+        instr.iter().map(|ii| ii.direction.to_vec2d() * ii.steps),
+    )
+    .ok_or_else(|| ParsingError::unexpected(last, line, line, "a plan that returns to the start"))
+}
+
+pub struct Day18 {
+    /// Trench outline, read from the plain and the hex-encoded plan
+    plan: Polygon,
+    hex_plan: Polygon,
+}
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+
+    fn parse(input: &str) -> aoc_common::Result<Day18> {
+        Ok(Day18 {
+            plan: parse(input, false)?,
+            hex_plan: parse(input, true)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        self.plan.lattice_points().into()
+    }
+
+    fn part_two(&self) -> Answer {
+        self.hex_plan.lattice_points().into()
+    }
+}
@@ -0,0 +1,58 @@
+use aoc2023_day18::Day18;
+use aoc_common::{ParsingError, Position, Solution};
+
+fn unknown_direction(input: &str) -> (Position, String) {
+    match Day18::parse(input) {
+        Err(ParsingError::UnknownDirection { position, token }) => (position, token),
+        Err(e) => panic!("unexpected error {e}"),
+        Ok(_) => panic!("{input:?} parsed"),
+    }
+}
+
+#[test]
+fn plain_plan_takes_letters_only() {
+    for letter in ["N", "0", "3", "RR"] {
+        let input = format!("R 6 (#70c710)\n{} 5 (#0dc571)\n", letter);
+        let (position, token) = unknown_direction(&input);
+        assert_eq!(position, Position { line: 2, column: 1 });
+        assert_eq!(token, letter);
+    }
+}
+
+#[test]
+fn hex_plan_takes_digit_codes_only() {
+    for code in ["R", "U", "N", "4"] {
+        let input = format!("R 6 (#70c71{})\nL 6 (#70c712)\n", code);
+        let (position, token) = unknown_direction(&input);
+        assert_eq!(
+            position,
+            Position {
+                line: 1,
+                column: 12
+            }
+        );
+        assert_eq!(token, code);
+    }
+}
+
+#[test]
+fn rejects_plans_that_do_not_close() {
+    match Day18::parse("R 6 (#70c710)\nD 5 (#0dc571)\n") {
+        Err(ParsingError::UnexpectedToken {
+            position, expected, ..
+        }) => {
+            assert_eq!(position, Position { line: 2, column: 1 });
+            assert_eq!(expected, "a plan that returns to the start");
+        }
+        Err(e) => panic!("unexpected error {e}"),
+        Ok(_) => panic!("open plan parsed"),
+    }
+}
+
+#[test]
+fn rejects_empty_plans() {
+    assert!(matches!(
+        Day18::parse(""),
+        Err(ParsingError::Missing("dig plan"))
+    ));
+}
@@ -0,0 +1,358 @@
+use aoc_common::{parse_number, Answer, ParsingError, Solution};
+use std::collections::{HashMap, HashSet};
+use std::ops::{self, Index};
+
+const CATEGORIES: [&str; 4] = ["x", "m", "a", "s"];
+
+#[derive(Debug)]
+struct Part {
+    map: HashMap<String, i64>,
+}
+
+impl Part {
+    fn new(map: HashMap<String, i64>) -> Part {
+        Part { map }
+    }
+}
+
+impl Index<&str> for Part {
+    type Output = i64;
+    fn index(&self, index: &str) -> &Self::Output {
+        self.map.get(index).expect("Unexpected part key")
+    }
+}
+
+#[derive(Debug)]
+enum Rule {
+    Mid(String, i64, bool, String),
+    Final(String),
+}
+
+#[derive(Debug)]
+struct Workflow {
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug, Clone)]
+struct Range {
+    low: i64,
+    high: i64,
+    label: String,
+    is_empty: bool,
+}
+
+impl ops::MulAssign<Range> for Range {
+    fn mul_assign(&mut self, rhs: Range) {
+        if self.high < rhs.low || self.low > rhs.high {
+            self.is_empty = true;
+        } else if self.high >= rhs.low {
+            self.low = self.low.max(rhs.low);
+            self.high = self.high.min(rhs.high);
+        }
+    }
+}
+
+impl Range {
+    fn new(label: &str) -> Range {
+        Range {
+            low: Range::LOW_LIMIT,
+            high: Range::HIGH_LIMIT,
+            label: label.to_string(),
+            is_empty: false,
+        }
+    }
+
+    fn count(&self) -> i64 {
+        if !self.is_empty {
+            self.high - self.low + 1
+        } else {
+            0
+        }
+    }
+
+    const LOW_LIMIT: i64 = 1;
+    const HIGH_LIMIT: i64 = 4000;
+}
+
+type Ranges = HashMap<String, Range>;
+
+struct Processor<'a> {
+    workflows: &'a HashMap<String, Workflow>,
+}
+
+impl<'a> Processor<'a> {
+    /// First range in the tuple matches the rule, the second one passes on
+    fn dispatch(&self, target: &str, ranges: Ranges) -> i64 {
+        match target {
+            "A" => ranges.values().map(|r| r.count()).product(),
+            "R" => 0,
+            _ => {
+                let new_workflows = self.workflows.get(target).unwrap().rules.as_slice();
+                self.step(ranges, new_workflows)
+            }
+        }
+    }
+
+    fn step(&self, ranges: Ranges, rules: &[Rule]) -> i64 {
+        match rules {
+            [head, tail @ ..] => {
+                if let Rule::Final(target) = head {
+                    self.dispatch(target, ranges)
+                } else {
+                    let (matches, passes, target) = rule_to_ranges(head);
+                    let mut ranges_left = ranges.clone();
+                    *ranges_left.get_mut(&matches.label).unwrap() *= matches;
+
+                    let mut ranges_right = ranges.clone();
+                    *ranges_right.get_mut(&passes.label).unwrap() *= passes;
+
+                    self.dispatch(target.as_str(), ranges_left) + self.step(ranges_right, tail)
+                }
+            }
+            _ => panic!(),
+        }
+    }
+}
+
+fn rule_to_ranges(rule: &Rule) -> (Range, Range, String) {
+    match rule {
+        Rule::Mid(what, thr, is_less_than_thr, target) => {
+            if *is_less_than_thr {
+                (
+                    Range {
+                        is_empty: false,
+                        low: Range::LOW_LIMIT,
+                        high: thr - 1,
+                        label: what.to_string(),
+                    },
+                    Range {
+                        is_empty: false,
+                        low: *thr,
+                        high: Range::HIGH_LIMIT,
+                        label: what.to_string(),
+                    },
+                    target.to_string(),
+                )
+            } else {
+                (
+                    Range {
+                        is_empty: false,
+                        low: thr + 1,
+                        high: Range::HIGH_LIMIT,
+                        label: what.to_string(),
+                    },
+                    Range {
+                        is_empty: false,
+                        low: Range::LOW_LIMIT,
+                        high: *thr,
+                        label: what.to_string(),
+                    },
+                    target.to_string(),
+                )
+            }
+        }
+        Rule::Final(_) => panic!("Final rule not expected here."),
+    }
+}
+
+fn part_to_ranges(part: &Part) -> Ranges {
+    let mut ranges = HashMap::new();
+    for (key, value) in part.map.iter() {
+        ranges.insert(
+            key.to_string(),
+            Range {
+                label: key.to_owned(),
+                low: *value,
+                high: *value,
+                is_empty: false,
+            },
+        );
+    }
+    ranges
+}
+
+fn category<'a>(index: usize, line: &str, token: &'a str) -> aoc_common::Result<&'a str> {
+    if CATEGORIES.contains(&token) {
+        Ok(token)
+    } else {
+        Err(ParsingError::unexpected(
+            index,
+            line,
+            token,
+            "one of x, m, a, s",
+        ))
+    }
+}
+
+fn parse(input: &str) -> aoc_common::Result<(HashMap<String, Workflow>, Vec<Part>)> {
+    let mut workflows = HashMap::new();
+    let mut items = Vec::new();
+    let mut targets = Vec::new();
+
+    for (index, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+        let Some((name, rest)) = line.split_once('{') else {
+            return Err(ParsingError::unexpected(
+                index,
+                line,
+                line,
+                "a workflow or a part",
+            ));
+        };
+        let Some(body) = rest.strip_suffix('}') else {
+            return Err(ParsingError::unexpected(index, line, rest, "a closing '}'"));
+        };
+
+        if name.is_empty() {
+            let mut map = HashMap::new();
+            for chunk in body.split(',') {
+                let Some((what, rating)) = chunk.split_once('=') else {
+                    return Err(ParsingError::unexpected(index, line, chunk, "a rating"));
+                };
+                let what = category(index, line, what)?;
+                map.insert(what.to_string(), parse_number::<i64>(index, line, rating)?);
+            }
+            if map.len() != CATEGORIES.len() {
+                return Err(ParsingError::unexpected(
+                    index,
+                    line,
+                    line,
+                    "all four ratings",
+                ));
+            }
+            items.push(Part::new(map));
+        } else {
+            let rules: Vec<Rule> = body
+                .split(',')
+                .map(|rule| parse_rule(index, line, rule))
+                .collect::<aoc_common::Result<_>>()?;
+            if !matches!(rules.last(), Some(Rule::Final(_))) {
+                return Err(ParsingError::unexpected(
+                    index,
+                    line,
+                    body,
+                    "a fallback rule",
+                ));
+            }
+            for rule in body.split(',') {
+                let target = rule.split_once(':').map_or(rule, |(_, target)| target);
+                targets.push((index, line, name, target));
+            }
+
+            workflows.insert(name.to_string(), Workflow { rules });
+        }
+    }
+
+    let mut outgoing: HashMap<&str, Vec<(usize, &str, &str)>> = HashMap::new();
+    for (index, line, name, target) in targets {
+        if target != "A" && target != "R" && !workflows.contains_key(target) {
+            return Err(ParsingError::unknown_target(index, line, target));
+        }
+        outgoing
+            .entry(name)
+            .or_default()
+            .push((index, line, target));
+    }
+    if !workflows.contains_key("in") {
+        return Err(ParsingError::Missing("workflow 'in'"));
+    }
+    check_loops(&outgoing, "in", &mut Vec::new(), &mut HashSet::new())?;
+
+    Ok((workflows, items))
+}
+
+/// Fails on the first rule, reachable from workflow `name`, that leads back to a workflow on
+/// `path`, the workflows a part went through to get to `name`.
+fn check_loops<'a>(
+    outgoing: &HashMap<&'a str, Vec<(usize, &'a str, &'a str)>>,
+    name: &'a str,
+    path: &mut Vec<&'a str>,
+    done: &mut HashSet<&'a str>,
+) -> aoc_common::Result<()> {
+    if done.contains(name) {
+        return Ok(());
+    }
+    path.push(name);
+    for &(index, line, target) in outgoing.get(name).into_iter().flatten() {
+        if path.contains(&target) {
+            return Err(ParsingError::loop_back(index, line, name, target));
+        }
+        check_loops(outgoing, target, path, done)?;
+    }
+    path.pop();
+    done.insert(name);
+    Ok(())
+}
+
+fn process(workflows: &HashMap<String, Workflow>, items: &[Part], part_two: bool) -> i64 {
+    let proc = Processor { workflows };
+
+    if !part_two {
+        items
+            .iter()
+            .map(|part| {
+                let ranges = part_to_ranges(part);
+                if proc.step(ranges, workflows.get("in").unwrap().rules.as_slice()) > 0 {
+                    part.map.values().sum()
+                } else {
+                    0
+                }
+            })
+            .sum()
+    } else {
+        let mut full_ranges = HashMap::new();
+        for name in CATEGORIES {
+            full_ranges.insert(name.to_string(), Range::new(name));
+        }
+
+        proc.step(full_ranges, workflows.get("in").unwrap().rules.as_slice())
+    }
+}
+
+fn parse_rule(index: usize, line: &str, rule: &str) -> aoc_common::Result<Rule> {
+    if let Some((cond, target)) = rule.split_once(':') {
+        if let Some((what, thr)) = cond.split_once('<') {
+            Ok(Rule::Mid(
+                category(index, line, what)?.to_string(),
+                parse_number(index, line, thr)?,
+                true,
+                target.to_string(),
+            ))
+        } else if let Some((what, thr)) = cond.split_once('>') {
+            Ok(Rule::Mid(
+                category(index, line, what)?.to_string(),
+                parse_number(index, line, thr)?,
+                false,
+                target.to_string(),
+            ))
+        } else {
+            Err(ParsingError::unexpected(index, line, cond, "a condition"))
+        }
+    } else {
+        Ok(Rule::Final(rule.to_string()))
+    }
+}
+
+pub struct Day19 {
+    workflows: HashMap<String, Workflow>,
+    items: Vec<Part>,
+}
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+
+    fn parse(input: &str) -> aoc_common::Result<Day19> {
+        let (workflows, items) = parse(input)?;
+        Ok(Day19 { workflows, items })
+    }
+
+    fn part_one(&self) -> Answer {
+        process(&self.workflows, &self.items, false).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        process(&self.workflows, &self.items, true).into()
+    }
+}
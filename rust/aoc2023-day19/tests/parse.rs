@@ -0,0 +1,32 @@
+use aoc2023_day19::Day19;
+use aoc_common::{ParsingError, Position, Solution};
+
+const PART: &str = "\n{x=1,m=2,a=3,s=4}\n";
+
+fn loop_back(workflows: &str) -> (Position, String, String) {
+    match Day19::parse(&format!("{}{}", workflows, PART)) {
+        Err(ParsingError::LoopBack {
+            position,
+            from,
+            token,
+        }) => (position, from, token),
+        Err(e) => panic!("unexpected error {e}"),
+        Ok(_) => panic!("{workflows:?} parsed"),
+    }
+}
+
+#[test]
+fn workflows_must_not_loop() {
+    let (position, from, token) = loop_back("in{x<10:a,R}\na{m>5:in,A}\n");
+    assert_eq!(position, Position { line: 2, column: 7 });
+    assert_eq!((from.as_str(), token.as_str()), ("a", "in"));
+
+    let (_, from, token) = loop_back("in{x<10:a,R}\na{m>5:b,A}\nb{b}\n");
+    assert_eq!((from.as_str(), token.as_str()), ("b", "b"));
+}
+
+#[test]
+fn shared_workflows_are_not_loops() {
+    let day = Day19::parse(&format!("in{{x<10:a,b}}\na{{m>5:b,A}}\nb{{R}}\n{}", PART)).unwrap();
+    assert_eq!(day.part_one(), 10u64.into());
+}
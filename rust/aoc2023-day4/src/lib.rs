@@ -0,0 +1,76 @@
+use aoc_common::{Answer, ParsingError, Solution};
+use atoi::atoi;
+use regex::Regex;
+use std::collections::HashSet;
+
+/// Returns (number of points, number of matching numbers)
+fn extract_points(index: usize, line: &str, re: &Regex) -> aoc_common::Result<(u32, u32)> {
+    if let Some((_, [_, winners, numbers])) = re.captures_iter(line).map(|c| c.extract()).next() {
+        // let card: u32 = atoi::<u32>(card.as_bytes()).unwrap();
+        let winners: Vec<u32> = winners
+            .trim()
+            .split(' ')
+            .filter_map(|s| atoi::<u32>(s.as_bytes()))
+            .collect();
+        let numbers: Vec<u32> = numbers
+            .trim()
+            .split(' ')
+            .filter_map(|s| atoi::<u32>(s.as_bytes()))
+            .collect();
+        // println!("Winners: {:?}", winners);
+        // println!("Numbers: {:?}", numbers);
+
+        let winners_set: HashSet<&u32> = HashSet::from_iter(winners.iter());
+        let wins: u32 = numbers
+            .iter()
+            .map(|x| if winners_set.contains(x) { 1 } else { 0 })
+            .sum();
+        return Ok((if wins >= 1 { 2_u32.pow(wins - 1) } else { 0 }, wins));
+    }
+    Err(ParsingError::unexpected(index, line, line, "a scratchcard"))
+}
+
+fn count_cards(wins: &[u32]) -> u32 {
+    let mut counts: Vec<u32> = vec![1; wins.len()];
+
+    for index in 0..wins.len() {
+        for _j in 0..counts[index] {
+            let mut k = index + 1;
+            while k < wins.len() && k <= index + wins[index] as usize {
+                counts[k] += 1;
+                k += 1;
+            }
+        }
+    }
+
+    counts.iter().sum::<u32>()
+}
+
+pub struct Day4 {
+    /// (number of points, number of matching numbers) for every card
+    cards: Vec<(u32, u32)>,
+}
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+
+    fn parse(input: &str) -> aoc_common::Result<Day4> {
+        let re = Regex::new(r"Card\s+(\d+):\s+((?:\d+\s+)+)\|((?:\s+\d+)+)").unwrap();
+        let cards = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| extract_points(index, line, &re))
+            .collect::<aoc_common::Result<_>>()?;
+        Ok(Day4 { cards })
+    }
+
+    fn part_one(&self) -> Answer {
+        self.cards.iter().map(|card| card.0).sum::<u32>().into()
+    }
+
+    fn part_two(&self) -> Answer {
+        let wins: Vec<u32> = self.cards.iter().map(|card| card.1).collect();
+        count_cards(&wins).into()
+    }
+}
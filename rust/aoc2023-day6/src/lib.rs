@@ -0,0 +1,117 @@
+use aoc_common::{parse_number, Answer, ParsingError, Position, Solution};
+use regex::Regex;
+
+#[derive(Debug)]
+struct Game {
+    time: u128,
+    distance: u128,
+}
+
+fn parse(input: &str, concatenate: bool) -> aoc_common::Result<Vec<Game>> {
+    let re = Regex::new(r"(\w+)\s*:((?:\s+\d+)+)").unwrap();
+
+    let mut times: Vec<u128> = vec![];
+    let mut distances: Vec<u128> = vec![];
+    let mut distance_line = (0, "");
+
+    for (index, line) in input.lines().enumerate() {
+        if let Some((_, [header, values])) = re.captures_iter(line).map(|c| c.extract()).next() {
+            let values = if !concatenate {
+                values
+                    .split_whitespace()
+                    .map(|x| parse_number::<u128>(index, line, x))
+                    .collect::<aoc_common::Result<Vec<_>>>()?
+            } else {
+                let digits: String = values.split_whitespace().collect();
+                let value =
+                    digits
+                        .parse::<u128>()
+                        .map_err(|source| ParsingError::InvalidNumber {
+                            position: Position::locate(index, line, values.trim()),
+                            token: digits.clone(),
+                            source,
+                        })?;
+                vec![value]
+            };
+            match header {
+                "Time" => times = values,
+                "Distance" => {
+                    distances = values;
+                    distance_line = (index, line);
+                }
+                &_ => {
+                    return Err(ParsingError::unexpected(
+                        index,
+                        line,
+                        header,
+                        "Time or Distance",
+                    ))
+                }
+            }
+        }
+    }
+
+    if times.is_empty() {
+        return Err(ParsingError::Missing("race times"));
+    }
+    if distances.is_empty() {
+        return Err(ParsingError::Missing("race distances"));
+    }
+    if times.len() != distances.len() {
+        let (index, line) = distance_line;
+        return Err(ParsingError::unexpected(
+            index,
+            line,
+            line,
+            "a distance for every race time",
+        ));
+    }
+
+    let games: Vec<_> = times
+        .iter()
+        .zip(distances.iter())
+        .map(|(t, d)| Game {
+            time: *t,
+            distance: *d,
+        })
+        .collect();
+
+    Ok(games)
+}
+
+fn process_game(game: &Game) -> u128 {
+    let numbers = (0..=game.time)
+        .map(|t| (game.time - t) * t)
+        .filter(|d| d > &game.distance)
+        .count();
+    numbers.try_into().unwrap()
+}
+
+pub struct Day6 {
+    games: Vec<Game>,
+    /// The single long race read by concatenating the digits on each line
+    long_games: Vec<Game>,
+}
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+
+    fn parse(input: &str) -> aoc_common::Result<Day6> {
+        Ok(Day6 {
+            games: parse(input, false)?,
+            long_games: parse(input, true)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        self.games.iter().map(process_game).product::<u128>().into()
+    }
+
+    fn part_two(&self) -> Answer {
+        self.long_games
+            .iter()
+            .map(process_game)
+            .product::<u128>()
+            .into()
+    }
+}
@@ -0,0 +1,15 @@
+use aoc2023_day6::Day6;
+use aoc_common::{ParsingError, Solution};
+
+#[test]
+fn every_race_needs_a_distance() {
+    for input in ["Time: 7 15\nDistance: 9\n", "Time: 7\nDistance: 9 40\n"] {
+        assert!(matches!(
+            Day6::parse(input),
+            Err(ParsingError::UnexpectedToken {
+                expected: "a distance for every race time",
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,22 @@
+use aoc2023_day7::Day7;
+use aoc_common::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const SEED: u64 = 2023;
+
+fn bench(c: &mut Criterion) {
+    let input = aoc_inputgen::day7(SEED, 10_000);
+    let day = Day7::parse(&input).unwrap();
+
+    let mut group = c.benchmark_group("day7 10k hands");
+    group.sample_size(10);
+    group.bench_function("parse", |b| {
+        b.iter(|| Day7::parse(black_box(&input)).unwrap())
+    });
+    group.bench_function("part one", |b| b.iter(|| day.part_one()));
+    group.bench_function("part two", |b| b.iter(|| day.part_two()));
+    group.finish();
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
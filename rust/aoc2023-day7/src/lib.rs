@@ -0,0 +1,188 @@
+use aoc_common::{parse_number, Answer, ParsingError, Solution};
+use std::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
+use std::collections::HashMap;
+use std::mem::discriminant;
+
+#[derive(Copy, Clone)]
+enum HandOrdering {
+    Single = 1,
+    Pair,
+    TwoPair,
+    Triplet,
+    FullHouse,
+    Quad,
+    Quint,
+}
+
+struct Hand {
+    ordering: HandOrdering,
+    hand_numeric: i64,
+    bid: u64,
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        ((self.ordering as isize), self.hand_numeric)
+            .cmp(&((other.ordering as isize), other.hand_numeric))
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        (discriminant(&self.ordering), self.hand_numeric)
+            == (discriminant(&other.ordering), other.hand_numeric)
+    }
+}
+
+impl Eq for Hand {}
+
+impl Hand {
+    fn new(hand_string: &str, bid: u64, jokers: bool) -> Self {
+        let mut hand_hex = hand_string
+            .replace("A", "E")
+            .replace("T", "A")
+            .replace("J", "B")
+            .replace("Q", "C")
+            .replace("K", "D");
+
+        if jokers {
+            hand_hex = hand_hex.replace("B", "1");
+        }
+
+        let hand_numeric = i64::from_str_radix(&hand_hex, 16).unwrap();
+
+        let mut cards_sorted: Vec<_> = hand_hex.chars().collect();
+        // let mut cards_sorted = numeric_cards.clone();
+        cards_sorted.sort();
+
+        let mut counts = HashMap::new();
+        for card in cards_sorted {
+            counts
+                .entry(card)
+                .and_modify(|count| *count += 1u32)
+                .or_insert(1u32);
+        }
+
+        let mut counts_vec: Vec<u32> = counts.values().copied().collect();
+        let joker_count: u32 = *(counts.get(&'1').unwrap_or(&0));
+
+        counts_vec.sort();
+        counts_vec.reverse();
+
+        let mut ordering = match counts_vec[0] {
+            5 => HandOrdering::Quint,
+            4 => HandOrdering::Quad,
+            3 => {
+                if counts_vec[1] == 2 {
+                    HandOrdering::FullHouse
+                } else {
+                    HandOrdering::Triplet
+                }
+            }
+            2 => {
+                if counts_vec[1] == 2 {
+                    HandOrdering::TwoPair
+                } else {
+                    HandOrdering::Pair
+                }
+            }
+            _ => HandOrdering::Single,
+        };
+
+        if jokers && joker_count > 0 {
+            ordering = match ordering {
+                HandOrdering::Quint => HandOrdering::Quint,
+                HandOrdering::Quad => HandOrdering::Quint,
+                HandOrdering::FullHouse => HandOrdering::Quint,
+                HandOrdering::TwoPair => {
+                    if joker_count == 1 {
+                        HandOrdering::FullHouse
+                    } else {
+                        HandOrdering::Quad
+                    }
+                }
+                HandOrdering::Triplet => HandOrdering::Quad,
+                HandOrdering::Pair => HandOrdering::Triplet,
+                HandOrdering::Single => HandOrdering::Pair,
+            }
+        }
+
+        Self {
+            bid,
+            ordering,
+            hand_numeric,
+        }
+    }
+}
+
+fn parse(input: &str, jokers: bool) -> aoc_common::Result<Vec<Hand>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let tokens: Vec<_> = line.split_whitespace().collect();
+            let [hand, bid] = tokens[..] else {
+                return Err(ParsingError::unexpected(
+                    index,
+                    line,
+                    line,
+                    "a hand and a bid",
+                ));
+            };
+            if hand.chars().count() != 5 {
+                return Err(ParsingError::unexpected(
+                    index,
+                    line,
+                    hand,
+                    "a hand of five cards",
+                ));
+            }
+            if let Some(card) = hand.matches(|c| !"23456789TJQKA".contains(c)).next() {
+                return Err(ParsingError::unexpected(index, line, card, "a card"));
+            }
+            Ok(Hand::new(hand, parse_number(index, line, bid)?, jokers))
+        })
+        .collect()
+}
+
+fn winnings(hands: &[Hand]) -> u64 {
+    hands
+        .iter()
+        .enumerate()
+        .map(|(rank, hand)| (rank as u64 + 1) * hand.bid)
+        .sum()
+}
+
+pub struct Day7 {
+    /// Hands sorted by strength, without and with jokers
+    hands: Vec<Hand>,
+    joker_hands: Vec<Hand>,
+}
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+
+    fn parse(input: &str) -> aoc_common::Result<Day7> {
+        let mut hands = parse(input, false)?;
+        let mut joker_hands = parse(input, true)?;
+
+        hands.sort();
+        joker_hands.sort();
+
+        Ok(Day7 { hands, joker_hands })
+    }
+
+    fn part_one(&self) -> Answer {
+        winnings(&self.hands).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        winnings(&self.joker_hands).into()
+    }
+}
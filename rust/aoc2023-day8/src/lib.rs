@@ -0,0 +1,273 @@
+use aoc_common::{cycle, Answer, ParsingError, Part, Solution};
+use num::integer::{lcm, ExtendedGcd, Integer};
+use regex::Regex;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+struct Node {
+    left: String,
+    right: String,
+}
+
+#[derive(Debug)]
+struct Problem {
+    nodes: HashMap<String, Node>,
+    instructions: Instructions,
+}
+
+#[derive(Debug)]
+struct Instructions {
+    sequence: Vec<char>,
+}
+
+impl Instructions {
+    pub fn iter(&self) -> InstructionIterator<'_> {
+        InstructionIterator {
+            data: self,
+            position: -1,
+        }
+    }
+}
+
+struct InstructionIterator<'a> {
+    data: &'a Instructions,
+    position: i32,
+}
+
+impl<'a> Iterator for InstructionIterator<'a> {
+    type Item = &'a char;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.position = (self.position + 1) % self.data.sequence.len() as i32;
+        Some(&self.data.sequence[self.position as usize])
+    }
+}
+
+fn parse(input: &str) -> aoc_common::Result<Problem> {
+    let mut lines = input.lines().enumerate();
+
+    let (_, first_line) = lines.next().ok_or(ParsingError::Missing("instructions"))?;
+    let sequence = first_line.trim();
+    if sequence.is_empty() {
+        return Err(ParsingError::Missing("instructions"));
+    }
+    if let Some(instr) = sequence.matches(|c| c != 'L' && c != 'R').next() {
+        return Err(ParsingError::unknown_direction(0, first_line, instr));
+    }
+    let instructions: Vec<_> = sequence.chars().collect();
+
+    let instructions = Instructions {
+        sequence: instructions,
+    };
+
+    let re = Regex::new(r"(?<source>\w\w\w) = \((?<left>\w\w\w), (?<right>\w\w\w)\)").unwrap();
+
+    let mut dict = HashMap::new();
+    let mut edges = Vec::new();
+
+    for (index, line) in lines {
+        if let Some(captures) = re.captures(line) {
+            let source = captures["source"].to_string();
+            let left = captures.name("left").unwrap().as_str();
+            let right = captures.name("right").unwrap().as_str();
+
+            edges.push((index, line, left));
+            edges.push((index, line, right));
+
+            dict.insert(
+                source,
+                Node {
+                    left: left.to_string(),
+                    right: right.to_string(),
+                },
+            );
+        } else if !line.trim().is_empty() {
+            return Err(ParsingError::unexpected(index, line, line, "a node"));
+        }
+    }
+
+    if let Some((index, line, target)) = edges.iter().find(|(_, _, t)| !dict.contains_key(*t)) {
+        return Err(ParsingError::unknown_target(*index, line, target));
+    }
+
+    Ok(Problem {
+        instructions,
+        nodes: dict,
+    })
+}
+
+/// Steps from `start_label` to the first node matching `end_predicate`, or `None` if the walk
+/// never gets there.
+///
+/// The walk is decided by its node and place in the instructions, so once it has taken more steps
+/// than there are such states it is going round in circles.
+fn walk_the_tree(
+    start_label: &str,
+    end_predicate: impl Fn(&str) -> bool,
+    problem: &Problem,
+) -> Option<i64> {
+    let mut counter: i64 = 0;
+    let mut label = start_label;
+    let mut instr_iter = problem.instructions.iter();
+    let states = (problem.nodes.len() * problem.instructions.sequence.len()) as i64;
+
+    loop {
+        if end_predicate(label) {
+            break;
+        }
+        if counter > states {
+            return None;
+        }
+
+        let instr = *(instr_iter.next().unwrap());
+        let node = problem.nodes.get(label)?;
+
+        if instr == 'L' {
+            label = &node.left;
+        } else {
+            label = &node.right;
+        }
+        counter += 1;
+    }
+
+    Some(counter)
+}
+
+/// Times at which a ghost stands on a Z node: `start` alone when `period` is zero, otherwise
+/// `start`, `start + period`, `start + 2 * period` and so on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Hits {
+    start: i128,
+    period: i128,
+}
+
+impl Hits {
+    /// Times that are in both sets, if any.
+    fn intersect(self, other: Hits) -> Option<Hits> {
+        let (a, b) = if self.period == 0 {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        if a.period == 0 {
+            let on_b = if b.period == 0 {
+                a.start == b.start
+            } else {
+                a.start >= b.start && (a.start - b.start) % b.period == 0
+            };
+            return on_b.then_some(a);
+        }
+
+        // t = a.start + k * a.period = b.start (mod b.period), solved for k
+        let ExtendedGcd { gcd, x, .. } = a.period.extended_gcd(&b.period);
+        let offset = b.start - a.start;
+        if offset % gcd != 0 {
+            return None;
+        }
+        let modulus = b.period / gcd;
+        let k = (offset / gcd * x).mod_floor(&modulus);
+        let period = lcm(a.period, b.period);
+        let first = a.start + k * a.period;
+        let earliest = a.start.max(b.start);
+        let start = first + Integer::div_ceil(&(earliest - first), &period).max(0) * period;
+        Some(Hits { start, period })
+    }
+}
+
+/// Every time the ghost starting at `start_label` stands on a node ending in Z.
+///
+/// The ghost's state is its node and its place in the instructions, so it must come back to an
+/// earlier state and from then on repeat; Z nodes before that are only passed once.
+fn ghost_hits(start_label: &str, problem: &Problem) -> Vec<Hits> {
+    let sequence = &problem.instructions.sequence;
+    let step = |&(label, position): &(&str, usize)| {
+        let node = &problem.nodes[label];
+        let next = if sequence[position] == 'L' {
+            &node.left
+        } else {
+            &node.right
+        };
+        (next.as_str(), (position + 1) % sequence.len())
+    };
+    let (found, states) = cycle::hashed((start_label, 0), step);
+
+    states
+        .iter()
+        .enumerate()
+        .filter(|(_, (label, _))| label.ends_with('Z'))
+        .map(|(time, _)| Hits {
+            start: time as i128,
+            period: if time < found.prefix {
+                0
+            } else {
+                found.length as i128
+            },
+        })
+        .collect()
+}
+
+pub struct Day8 {
+    problem: Problem,
+}
+
+impl Day8 {
+    /// Part one walks from AAA to ZZZ, which inputs written for part two need not have.
+    fn check(self, part: Part) -> aoc_common::Result<Day8> {
+        if part == Part::One {
+            if !self.problem.nodes.contains_key("AAA") {
+                return Err(ParsingError::Missing("node AAA"));
+            }
+            if !self.problem.nodes.contains_key("ZZZ") {
+                return Err(ParsingError::Missing("node ZZZ"));
+            }
+        }
+        Ok(self)
+    }
+}
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+
+    fn parse(input: &str) -> aoc_common::Result<Day8> {
+        Ok(Day8 {
+            problem: parse(input)?,
+        })
+    }
+
+    fn solve(input: &str, part: Part) -> aoc_common::Result<Answer> {
+        Ok(Self::parse(input)?.check(part)?.answer(part))
+    }
+
+    fn solve_file(file_path: &str, part: Part) -> aoc_common::Result<Answer> {
+        Ok(Self::parse_file(file_path)?.check(part)?.answer(part))
+    }
+
+    fn part_one(&self) -> Answer {
+        match walk_the_tree("AAA", |label: &str| label == "ZZZ", &self.problem) {
+            Some(steps) => steps.into(),
+            None => "never".into(),
+        }
+    }
+
+    fn part_two(&self) -> Answer {
+        let mut together = vec![Hits {
+            start: 0,
+            period: 1,
+        }];
+        for start in self
+            .problem
+            .nodes
+            .keys()
+            .filter(|label| label.ends_with('A'))
+        {
+            let hits = ghost_hits(start, &self.problem);
+            together = together
+                .iter()
+                .flat_map(|so_far| hits.iter().filter_map(|hit| so_far.intersect(*hit)))
+                .collect();
+        }
+        match together.iter().map(|hits| hits.start).min() {
+            Some(steps) => steps.into(),
+            None => "never".into(),
+        }
+    }
+}
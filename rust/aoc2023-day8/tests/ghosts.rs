@@ -0,0 +1,104 @@
+use aoc2023_day8::Day8;
+use aoc_common::{Answer, ParsingError, Part, Solution};
+use std::collections::HashMap;
+
+/// Z at every even step from 2 on, after a one-step run-up.
+const RUN_UP: &str = "\
+11A = (11B, 11B)
+11B = (11Z, 11Z)
+11Z = (11B, 11B)
+";
+
+/// Z at steps 1, 4, 7 and so on, never on the start.
+const OFFSET: &str = "\
+22A = (22Z, 22Z)
+22Z = (22C, 22C)
+22C = (22D, 22D)
+22D = (22Z, 22Z)
+";
+
+/// Two Z nodes in a loop of four, at steps 1 and 2 modulo 4.
+const TWO_ZS: &str = "\
+33A = (33Z, 33Z)
+33Z = (3XZ, 3XZ)
+3XZ = (33B, 33B)
+33B = (33A, 33A)
+";
+
+/// Z at odd steps only, so never together with [`RUN_UP`].
+const ODD: &str = "\
+44A = (44Z, 44Z)
+44Z = (44B, 44B)
+44B = (44Z, 44Z)
+";
+
+fn input(ghosts: &[&str]) -> String {
+    format!("L\n\n{}", ghosts.concat())
+}
+
+/// Steps until all ghosts stand on Z nodes, by walking them together.
+fn brute_force(input: &str) -> u64 {
+    let nodes: HashMap<&str, &str> = input
+        .lines()
+        .skip(2)
+        .map(|line| (&line[0..3], &line[7..10]))
+        .collect();
+    let mut ghosts: Vec<&str> = nodes.keys().filter(|l| l.ends_with('A')).copied().collect();
+    let mut steps = 0;
+    while !ghosts.iter().all(|l| l.ends_with('Z')) {
+        for ghost in ghosts.iter_mut() {
+            *ghost = nodes[ghost];
+        }
+        steps += 1;
+    }
+    steps
+}
+
+#[test]
+fn ghosts_with_run_ups_and_offsets() {
+    for (ghosts, expected) in [
+        (vec![RUN_UP], 2),
+        (vec![OFFSET], 1),
+        (vec![TWO_ZS], 1),
+        (vec![RUN_UP, OFFSET], 4),
+        (vec![OFFSET, TWO_ZS], 1),
+        (vec![RUN_UP, TWO_ZS], 2),
+        (vec![RUN_UP, OFFSET, TWO_ZS], 10),
+    ] {
+        let input = input(&ghosts);
+        assert_eq!(brute_force(&input), expected);
+        let day = Day8::parse(&input).unwrap();
+        assert_eq!(day.part_two(), Answer::from(expected), "{input}");
+    }
+}
+
+#[test]
+fn ghosts_that_never_line_up() {
+    let day = Day8::parse(&input(&[RUN_UP, ODD])).unwrap();
+    assert_eq!(day.part_two(), Answer::from("never"));
+}
+
+#[test]
+fn part_one_needs_aaa_and_zzz() {
+    let input = input(&[RUN_UP]);
+    assert!(matches!(
+        Day8::solve(&input, Part::One),
+        Err(ParsingError::Missing("node AAA"))
+    ));
+    assert_eq!(Day8::solve(&input, Part::Two).unwrap(), Answer::from(2));
+
+    let input = "L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\n";
+    assert!(matches!(
+        Day8::solve(input, Part::One),
+        Err(ParsingError::Missing("node ZZZ"))
+    ));
+}
+
+#[test]
+fn part_one_when_zzz_is_out_of_reach() {
+    let input = "LR\n\nAAA = (BBB, AAA)\nBBB = (AAA, BBB)\nZZZ = (ZZZ, ZZZ)\n";
+    assert_eq!(
+        Day8::solve(input, Part::One).unwrap(),
+        Answer::from("never")
+    );
+}
@@ -0,0 +1,22 @@
+use aoc2023_day9::Day9;
+use aoc_common::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const SEED: u64 = 2023;
+
+fn bench(c: &mut Criterion) {
+    let input = aoc_inputgen::day9(SEED, 10_000);
+    let day = Day9::parse(&input).unwrap();
+
+    let mut group = c.benchmark_group("day9 10k sequences");
+    group.sample_size(10);
+    group.bench_function("parse", |b| {
+        b.iter(|| Day9::parse(black_box(&input)).unwrap())
+    });
+    group.bench_function("part one", |b| b.iter(|| day.part_one()));
+    group.bench_function("part two", |b| b.iter(|| day.part_two()));
+    group.finish();
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
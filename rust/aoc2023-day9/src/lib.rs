@@ -0,0 +1,89 @@
+use aoc_common::{parse_number, Answer, ParsingError, Solution};
+
+type Sequence = Vec<i64>;
+
+fn parse(input: &str) -> aoc_common::Result<Vec<Sequence>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            if line.trim().is_empty() {
+                return Err(ParsingError::unexpected(
+                    index,
+                    line,
+                    line,
+                    "a sequence of numbers",
+                ));
+            }
+            line.split_whitespace()
+                .map(|token| parse_number::<i64>(index, line, token))
+                .collect()
+        })
+        .collect()
+}
+
+fn extrapolate(seq: Sequence, backward: bool) -> i64 {
+    let mut derivatives: Vec<Sequence> = Vec::new();
+    derivatives.push(seq);
+    loop {
+        let newseq: Vec<i64> = derivatives
+            .last()
+            .unwrap()
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .collect();
+        derivatives.push(newseq);
+        if derivatives
+            .last()
+            .unwrap()
+            .iter()
+            .filter(|&x| *x != 0)
+            .count()
+            == 0
+        {
+            break;
+        }
+    }
+
+    if backward {
+        let res: i64 = derivatives
+            .into_iter()
+            .flat_map(|seq| seq.first().copied())
+            .rev()
+            .reduce(|x, y| y - x)
+            .unwrap();
+        res
+    } else {
+        derivatives.iter().flat_map(|seq| seq.last()).sum()
+    }
+}
+
+pub struct Day9 {
+    sequences: Vec<Sequence>,
+}
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+
+    fn parse(input: &str) -> aoc_common::Result<Day9> {
+        Ok(Day9 {
+            sequences: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        self.sequences
+            .iter()
+            .map(|x| extrapolate(x.clone(), false))
+            .sum::<i64>()
+            .into()
+    }
+
+    fn part_two(&self) -> Answer {
+        self.sequences
+            .iter()
+            .map(|x| extrapolate(x.clone(), true))
+            .sum::<i64>()
+            .into()
+    }
+}
@@ -0,0 +1,17 @@
+use aoc2023_day9::Day9;
+use aoc_common::{ParsingError, Position, Solution};
+
+#[test]
+fn blank_lines_are_not_sequences() {
+    for input in ["0 3 6\n\n1 2 3\n", "0 3 6\n  \n"] {
+        match Day9::parse(input) {
+            Err(ParsingError::UnexpectedToken {
+                position, expected, ..
+            }) => {
+                assert_eq!(position, Position { line: 2, column: 1 });
+                assert_eq!(expected, "a sequence of numbers");
+            }
+            _ => panic!("{input:?} parsed"),
+        }
+    }
+}
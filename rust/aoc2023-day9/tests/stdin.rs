@@ -0,0 +1,23 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+#[test]
+fn dash_reads_the_input_from_stdin() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc2023-day9"))
+        .arg("-")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(b"0 3 6\n").unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    assert!(output.status.success(), "{:?}", output);
+    let table = String::from_utf8_lossy(&output.stdout);
+    let rows: Vec<Vec<&str>> = table
+        .lines()
+        .skip(1)
+        .map(|row| row.split_whitespace().take(3).collect())
+        .collect();
+    assert_eq!(rows, [["stdin", "1", "9"], ["stdin", "2", "-3"]]);
+}
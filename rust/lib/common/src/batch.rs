@@ -0,0 +1,218 @@
+use crate::inputs::{is_path, InputError, Inputs};
+use crate::{Answer, Part, Solution};
+use rayon::prelude::*;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum BatchError {
+    #[error("IO error: {0}")]
+    IOError(#[from] io::Error),
+    #[error("Invalid pattern: {0}")]
+    InvalidPattern(#[from] glob::PatternError),
+    #[error("No inputs match '{0}'")]
+    NoMatch(String),
+}
+
+/// Outcome of solving one part of one input.
+pub struct Row {
+    pub input: String,
+    pub part: Part,
+    pub answer: Result<Answer, String>,
+    pub time: Duration,
+}
+
+/// Expands `pattern` to input files: every file in a directory, the matches of a glob, or the
+/// file itself. Paths are sorted so tables come out in a stable order.
+pub fn inputs(pattern: &str) -> Result<Vec<PathBuf>, BatchError> {
+    let path = Path::new(pattern);
+    let mut paths = if path.is_dir() {
+        fs::read_dir(path)?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<io::Result<Vec<_>>>()?
+    } else if path.is_file() {
+        vec![path.to_path_buf()]
+    } else {
+        glob::glob(pattern)?
+            .filter_map(|entry| entry.ok())
+            .collect()
+    };
+    paths.retain(|p| p.is_file());
+    paths.sort();
+    if paths.is_empty() {
+        Err(BatchError::NoMatch(pattern.to_string()))
+    } else {
+        Ok(paths)
+    }
+}
+
+/// Reads standard input, which `-` stands for among the inputs.
+fn read_stdin() -> Result<String, String> {
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .map(|_| input)
+        .map_err(|e| format!("Cannot read stdin: {}", e))
+}
+
+fn solve<S: Solution>(path: &Path) -> Vec<Row> {
+    // stdin can only be read once, so both parts solve the same copy
+    let stdin = (path == Path::new("-")).then(read_stdin);
+    let label = match stdin {
+        Some(_) => "stdin".to_string(),
+        None => path.display().to_string(),
+    };
+    [Part::One, Part::Two]
+        .into_iter()
+        .map(|part| {
+            let start = Instant::now();
+            let answer = match &stdin {
+                Some(Ok(input)) => S::solve(input, part).map_err(|e| e.to_string()),
+                Some(Err(e)) => Err(e.clone()),
+                None => S::solve_file(&path.to_string_lossy(), part).map_err(|e| e.to_string()),
+            };
+            Row {
+                input: label.clone(),
+                part,
+                answer,
+                time: start.elapsed(),
+            }
+        })
+        .collect()
+}
+
+/// Solves both parts of every input, optionally spreading the inputs over threads.
+///
+/// Each part is timed including its own parse of the input.
+pub fn run<S: Solution>(inputs: &[PathBuf], parallel: bool) -> Vec<Row> {
+    if parallel {
+        inputs.par_iter().flat_map_iter(|p| solve::<S>(p)).collect()
+    } else {
+        inputs.iter().flat_map(|p| solve::<S>(p)).collect()
+    }
+}
+
+pub fn print_table(rows: &[Row]) {
+    let answers: Vec<String> = rows
+        .iter()
+        .map(|row| match &row.answer {
+            Ok(answer) => answer.to_string(),
+            Err(e) => format!("error: {}", e),
+        })
+        .collect();
+    let input_width = rows.iter().map(|r| r.input.len()).max().unwrap_or(0).max(5);
+    let answer_width = answers.iter().map(|a| a.len()).max().unwrap_or(0).max(6);
+
+    println!(
+        "{:<iw$}  part  {:<aw$}  time",
+        "input",
+        "answer",
+        iw = input_width,
+        aw = answer_width
+    );
+    for (row, answer) in rows.iter().zip(&answers) {
+        println!(
+            "{:<iw$}  {:<4}  {:<aw$}  {:?}",
+            row.input,
+            row.part.to_string(),
+            answer,
+            row.time,
+            iw = input_width,
+            aw = answer_width
+        );
+    }
+}
+
+/// Input files of day `S` for the command line `patterns`.
+///
+/// Paths are expanded as given, then inside the day's directory of the inputs root. Bare names
+/// are looked up, and expanded, at each of [`Inputs::candidates`] in turn. `-` stands for stdin,
+/// which is kept once however often it is given. Without patterns, whichever of `example1.txt`
+/// and `input.txt` can be found.
+pub fn collect_inputs<S: Solution>(
+    patterns: &[String],
+    locations: &Inputs,
+) -> Result<Vec<PathBuf>, String> {
+    if patterns.is_empty() {
+        return Ok(["example1.txt", "input.txt"]
+            .iter()
+            .filter_map(|name| locations.find(S::DAY, name).ok())
+            .collect());
+    }
+    let mut paths = Vec::new();
+    for pattern in patterns {
+        let found = if pattern == "-" {
+            if paths.iter().any(|path| path == Path::new("-")) {
+                continue;
+            }
+            vec![PathBuf::from("-")]
+        } else if is_path(pattern) {
+            inputs(pattern)
+                .or_else(|e| match locations.root() {
+                    Some(root) => {
+                        let day_dir = root.join(format!("day{:02}", S::DAY));
+                        inputs(&day_dir.join(pattern).to_string_lossy()).map_err(|_| e)
+                    }
+                    None => Err(e),
+                })
+                .map_err(|e| e.to_string())?
+        } else {
+            let searched = locations.candidates(S::DAY, pattern);
+            let found = searched
+                .iter()
+                .find_map(|candidate| inputs(&candidate.to_string_lossy()).ok());
+            found.ok_or_else(|| {
+                let e = InputError::NotFound {
+                    day: S::DAY,
+                    name: pattern.clone(),
+                    searched,
+                };
+                e.to_string()
+            })?
+        };
+        paths.extend(found);
+    }
+    Ok(paths)
+}
+
+/// Entry point shared by the day binaries; `crate_dir` is the day's `CARGO_MANIFEST_DIR`.
+///
+/// Usage: `dayN [--parallel] [--inputs DIR] [INPUT...]`, where each INPUT is a file, a directory,
+/// a glob or `-` for stdin, found as described for [`collect_inputs`]. Without inputs, solves whichever of
+/// `example1.txt` and `input.txt` exist.
+pub fn main<S: Solution>(crate_dir: &str) -> ExitCode {
+    let mut parallel = false;
+    let mut root = None;
+    let mut patterns = Vec::new();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-j" | "--parallel" => parallel = true,
+            "--inputs" => root = args.next().map(PathBuf::from),
+            _ => patterns.push(arg),
+        }
+    }
+
+    let paths = Inputs::new(root)
+        .map_err(|e| e.to_string())
+        .and_then(|locations| collect_inputs::<S>(&patterns, &locations.with_fallback(crate_dir)));
+    let paths = match paths {
+        Ok(paths) => paths,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let rows = run::<S>(&paths, parallel);
+    print_table(&rows);
+    if rows.iter().all(|row| row.answer.is_ok()) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,118 @@
+//! Where a deterministic sequence of states starts repeating.
+//!
+//! The sequence is `start`, `step(start)`, `step(step(start))` and so on. Each search runs until
+//! a state comes round again, so the states reachable from `start` must be finitely many.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Shape of an eventually periodic sequence: `prefix` states that never come back, then a loop
+/// of `length` states repeated forever.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Index below `prefix + length` of the state equal to state `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.length
+        }
+    }
+}
+
+/// Length of the prefix, found by walking two pointers `length` states apart until they meet.
+fn find_prefix<S: Clone + Eq>(start: &S, step: &mut impl FnMut(&S) -> S, length: usize) -> usize {
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+    prefix
+}
+
+/// Floyd's tortoise and hare, keeping only three states in memory.
+pub fn floyd<S: Clone + Eq>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+    let prefix = find_prefix(&start, &mut step, length);
+    Cycle { prefix, length }
+}
+
+/// Brent's algorithm, like [`floyd`] in memory but usually with fewer steps.
+pub fn brent<S: Clone + Eq>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+    let prefix = find_prefix(&start, &mut step, length);
+    Cycle { prefix, length }
+}
+
+/// Remembers every state, returning them in order up to the first repeat along with the cycle.
+pub fn hashed<S: Clone + Hash + Eq>(start: S, mut step: impl FnMut(&S) -> S) -> (Cycle, Vec<S>) {
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = start;
+    loop {
+        if let Some(&prefix) = seen.get(&state) {
+            let length = states.len() - prefix;
+            return (Cycle { prefix, length }, states);
+        }
+        seen.insert(state.clone(), states.len());
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+}
+
+/// State `n` of the sequence, skipping whole loops once a repeat shows up.
+pub fn nth_state<S: Clone + Hash + Eq>(start: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = start;
+    while states.len() < n {
+        if let Some(&prefix) = seen.get(&state) {
+            let cycle = Cycle {
+                prefix,
+                length: states.len() - prefix,
+            };
+            return states.swap_remove(cycle.reduce(n));
+        }
+        seen.insert(state.clone(), states.len());
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+    state
+}
@@ -0,0 +1,130 @@
+use std::fmt::{self, Display};
+use std::io;
+use std::num::ParseIntError;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Location in the puzzle input, both coordinates 1-based.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Position {
+    /// Position of `token` within `text`, the line with 0-based index `line`.
+    ///
+    /// Falls back to the first column if `token` is not a slice of `text`.
+    pub fn locate(line: usize, text: &str, token: &str) -> Position {
+        let start = text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize).wrapping_sub(start);
+        let column = match offset.checked_add(token.len()) {
+            Some(end) if end <= text.len() => offset + 1,
+            _ => 1,
+        };
+        Position {
+            line: line + 1,
+            column,
+        }
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum ParsingError {
+    #[error("IO error: {0}")]
+    IOError(#[from] io::Error),
+    #[error("{position}: invalid number '{token}'")]
+    InvalidNumber {
+        position: Position,
+        token: String,
+        source: ParseIntError,
+    },
+    #[error("{position}: unexpected '{token}', expected {expected}")]
+    UnexpectedToken {
+        position: Position,
+        token: String,
+        expected: &'static str,
+    },
+    #[error("{position}: unknown direction '{token}'")]
+    UnknownDirection { position: Position, token: String },
+    #[error("{position}: unknown target '{token}'")]
+    UnknownTarget { position: Position, token: String },
+    #[error("{position}: '{from}' leads back round a loop to '{token}'")]
+    LoopBack {
+        position: Position,
+        from: String,
+        token: String,
+    },
+    #[error("line {line}: grid row has {found} cells, expected {expected}")]
+    MalformedGrid {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("{position}: invalid cell '{cell}': {reason}")]
+    InvalidCell {
+        position: Position,
+        cell: char,
+        reason: String,
+    },
+    #[error("missing {0}")]
+    Missing(&'static str),
+}
+
+impl ParsingError {
+    pub fn unexpected(
+        line: usize,
+        text: &str,
+        token: &str,
+        expected: &'static str,
+    ) -> ParsingError {
+        ParsingError::UnexpectedToken {
+            position: Position::locate(line, text, token),
+            token: token.to_string(),
+            expected,
+        }
+    }
+
+    pub fn unknown_direction(line: usize, text: &str, token: &str) -> ParsingError {
+        ParsingError::UnknownDirection {
+            position: Position::locate(line, text, token),
+            token: token.to_string(),
+        }
+    }
+
+    pub fn unknown_target(line: usize, text: &str, token: &str) -> ParsingError {
+        ParsingError::UnknownTarget {
+            position: Position::locate(line, text, token),
+            token: token.to_string(),
+        }
+    }
+
+    /// `token` on the line of `from` closes a loop that would be followed forever.
+    pub fn loop_back(line: usize, text: &str, from: &str, token: &str) -> ParsingError {
+        ParsingError::LoopBack {
+            position: Position::locate(line, text, token),
+            from: from.to_string(),
+            token: token.to_string(),
+        }
+    }
+}
+
+/// Parses `token`, a slice of `text` on the line with 0-based index `line`, as a number.
+pub fn parse_number<T>(line: usize, text: &str, token: &str) -> Result<T, ParsingError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    token
+        .parse::<T>()
+        .map_err(|source| ParsingError::InvalidNumber {
+            position: Position::locate(line, text, token),
+            token: token.to_string(),
+            source,
+        })
+}
@@ -0,0 +1,137 @@
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Environment variable naming the inputs root.
+pub const INPUTS_ENV: &str = "AOC_INPUTS";
+/// Config file looked up in the current directory and its ancestors.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+#[derive(Error, Debug)]
+pub enum InputError {
+    #[error("IO error: {0}")]
+    IOError(#[from] io::Error),
+    #[error("Malformed {CONFIG_FILE}: {0}")]
+    Config(#[from] toml::de::Error),
+    #[error("Cannot find {name} for day {day}, searched:{}", list(.searched))]
+    NotFound {
+        day: u32,
+        name: String,
+        searched: Vec<PathBuf>,
+    },
+}
+
+fn list(paths: &[PathBuf]) -> String {
+    paths
+        .iter()
+        .map(|p| format!("\n  {}", p.display()))
+        .collect()
+}
+
+#[derive(Deserialize)]
+struct Config {
+    inputs: Option<PathBuf>,
+}
+
+/// Where to look for puzzle inputs.
+///
+/// Inputs live under a root as `dayNN/input.txt`, `dayNN/example1.txt` and so on. Files next to
+/// a day's crate (with examples under their old `test.txt`, `test2.txt` names) and in the current
+/// directory are still found as a fallback.
+#[derive(Clone, Debug, Default)]
+pub struct Inputs {
+    root: Option<PathBuf>,
+    fallback: Vec<PathBuf>,
+}
+
+impl Inputs {
+    /// Uses `root` if given, else `$AOC_INPUTS`, else the `inputs` key of the nearest `aoc.toml`.
+    ///
+    /// A relative `inputs` in `aoc.toml` is taken relative to the config file.
+    pub fn new(root: Option<PathBuf>) -> Result<Inputs, InputError> {
+        let root = match root.or_else(|| env::var_os(INPUTS_ENV).map(PathBuf::from)) {
+            Some(root) => Some(root),
+            None => config_root()?,
+        };
+        Ok(Inputs {
+            root,
+            fallback: Vec::new(),
+        })
+    }
+
+    /// Also searches `dir`, typically the day's crate directory.
+    pub fn with_fallback(mut self, dir: impl Into<PathBuf>) -> Inputs {
+        self.fallback.push(dir.into());
+        self
+    }
+
+    pub fn root(&self) -> Option<&Path> {
+        self.root.as_deref()
+    }
+
+    /// Every location searched for `name` of `day`, in order.
+    pub fn candidates(&self, day: u32, name: &str) -> Vec<PathBuf> {
+        let mut candidates = Vec::new();
+        if let Some(root) = &self.root {
+            candidates.push(root.join(format!("day{:02}", day)).join(name));
+        }
+        for dir in &self.fallback {
+            candidates.push(dir.join(name));
+            if let Some(legacy) = legacy_name(name) {
+                candidates.push(dir.join(legacy));
+            }
+        }
+        candidates.push(PathBuf::from(name));
+        candidates.dedup();
+        candidates
+    }
+
+    pub fn find(&self, day: u32, name: &str) -> Result<PathBuf, InputError> {
+        let searched = self.candidates(day, name);
+        match searched.iter().find(|path| path.is_file()) {
+            Some(path) => Ok(path.clone()),
+            None => Err(InputError::NotFound {
+                day,
+                name: name.to_string(),
+                searched,
+            }),
+        }
+    }
+}
+
+/// Whether `name` is a path in its own right, absolute or with a directory in it, rather than a
+/// name to look up through [`Inputs`].
+pub fn is_path(name: &str) -> bool {
+    let path = Path::new(name);
+    path.is_absolute() || path.components().count() > 1
+}
+
+/// `exampleN.txt` used to be called `test.txt` for the first example and `testN.txt` after that.
+fn legacy_name(name: &str) -> Option<String> {
+    let number: u32 = name
+        .strip_prefix("example")?
+        .strip_suffix(".txt")?
+        .parse()
+        .ok()?;
+    Some(match number {
+        1 => "test.txt".to_string(),
+        n => format!("test{}.txt", n),
+    })
+}
+
+fn config_root() -> Result<Option<PathBuf>, InputError> {
+    let cwd = env::current_dir()?;
+    let Some(file) = cwd
+        .ancestors()
+        .map(|dir| dir.join(CONFIG_FILE))
+        .find(|file| file.is_file())
+    else {
+        return Ok(None);
+    };
+    let config: Config = toml::from_str(&fs::read_to_string(&file)?)?;
+    let dir = file.parent().unwrap_or(&cwd);
+    Ok(config.inputs.map(|inputs| dir.join(inputs)))
+}
@@ -0,0 +1,10 @@
+mod answer;
+pub mod batch;
+pub mod cycle;
+mod error;
+pub mod inputs;
+mod solution;
+
+pub use answer::Answer;
+pub use error::{parse_number, ParsingError, Position};
+pub use solution::{Part, Result, Solution};
@@ -0,0 +1,71 @@
+use crate::Answer;
+use std::fmt::{self, Display};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+
+pub type Result<T> = std::result::Result<T, crate::ParsingError>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl FromStr for Part {
+    type Err = String;
+    fn from_str(s: &str) -> std::result::Result<Part, String> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Unknown part: {}", s)),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// A single day of the calendar: parse the puzzle input once, then solve either part.
+pub trait Solution: Sized {
+    /// Day of the month the puzzle was published on.
+    const DAY: u32;
+
+    fn parse(input: &str) -> Result<Self>;
+
+    /// Reads the whole of `reader` (a file, stdin, a pipe) and parses it.
+    fn parse_reader(mut reader: impl BufRead) -> Result<Self> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        Self::parse(&input)
+    }
+
+    fn parse_file(file_path: &str) -> Result<Self> {
+        Self::parse_reader(BufReader::new(File::open(file_path)?))
+    }
+
+    fn part_one(&self) -> Answer;
+
+    fn part_two(&self) -> Answer;
+
+    fn answer(&self, part: Part) -> Answer {
+        match part {
+            Part::One => self.part_one(),
+            Part::Two => self.part_two(),
+        }
+    }
+
+    fn solve(input: &str, part: Part) -> Result<Answer> {
+        Ok(Self::parse(input)?.answer(part))
+    }
+
+    fn solve_file(file_path: &str, part: Part) -> Result<Answer> {
+        Ok(Self::parse_file(file_path)?.answer(part))
+    }
+}
@@ -0,0 +1,150 @@
+use aoc_common::batch::{collect_inputs, inputs, run, BatchError, Row};
+use aoc_common::inputs::Inputs;
+use aoc_common::{parse_number, Answer, Part, Solution};
+use std::fs;
+use std::path::PathBuf;
+
+struct Sum(Vec<u64>);
+
+impl Solution for Sum {
+    const DAY: u32 = 0;
+
+    fn parse(input: &str) -> aoc_common::Result<Sum> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| parse_number(index, line, line))
+            .collect::<aoc_common::Result<_>>()
+            .map(Sum)
+    }
+
+    fn part_one(&self) -> Answer {
+        self.0.iter().sum::<u64>().into()
+    }
+
+    fn part_two(&self) -> Answer {
+        self.0.iter().product::<u64>().into()
+    }
+}
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-batch-{}-{}", name, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("b.txt"), "2\n3\n").unwrap();
+    fs::write(dir.join("a.txt"), "4\n5\n").unwrap();
+    fs::write(dir.join("bad.dat"), "x\n").unwrap();
+    dir
+}
+
+fn names(paths: &[PathBuf]) -> Vec<String> {
+    paths
+        .iter()
+        .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+        .collect()
+}
+
+fn summary(rows: &[Row]) -> Vec<(Part, Result<Answer, String>)> {
+    rows.iter().map(|r| (r.part, r.answer.clone())).collect()
+}
+
+#[test]
+fn inputs_expand_directories_and_globs() {
+    let dir = scratch_dir("inputs");
+    let in_dir = inputs(&dir.to_string_lossy()).unwrap();
+    let globbed = inputs(&dir.join("*.txt").to_string_lossy()).unwrap();
+    let missing = inputs(&dir.join("*.none").to_string_lossy());
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(names(&in_dir), ["a.txt", "b.txt", "bad.dat"]);
+    assert_eq!(names(&globbed), ["a.txt", "b.txt"]);
+    assert!(matches!(missing, Err(BatchError::NoMatch(_))));
+}
+
+#[test]
+fn run_solves_both_parts_of_every_input() {
+    let dir = scratch_dir("run");
+    let paths = inputs(&dir.to_string_lossy()).unwrap();
+    let sequential = run::<Sum>(&paths, false);
+    let parallel = run::<Sum>(&paths, true);
+    fs::remove_dir_all(&dir).unwrap();
+
+    let invalid = Err("line 1, column 1: invalid number 'x'".to_string());
+    assert_eq!(summary(&sequential), summary(&parallel));
+    assert_eq!(
+        summary(&sequential),
+        [
+            (Part::One, Ok(Answer::from(9u64))),
+            (Part::Two, Ok(Answer::from(20u64))),
+            (Part::One, Ok(Answer::from(5u64))),
+            (Part::Two, Ok(Answer::from(6u64))),
+            (Part::One, invalid.clone()),
+            (Part::Two, invalid),
+        ]
+    );
+}
+
+#[test]
+fn bare_names_are_looked_up_like_inputs() {
+    let dir = scratch_dir("names");
+    let root = dir.join("root");
+    let crate_dir = dir.join("crate");
+    fs::create_dir_all(root.join("day00")).unwrap();
+    fs::create_dir_all(&crate_dir).unwrap();
+    fs::write(root.join("day00/input.txt"), "1\n").unwrap();
+    fs::write(crate_dir.join("input.txt"), "2\n").unwrap();
+    fs::write(crate_dir.join("test.txt"), "3\n").unwrap();
+    fs::write(crate_dir.join("test2.txt"), "4\n").unwrap();
+
+    let locations = Inputs::new(Some(root.clone()))
+        .unwrap()
+        .with_fallback(&crate_dir);
+    let names = |patterns: &[&str]| {
+        let patterns: Vec<String> = patterns.iter().map(|p| p.to_string()).collect();
+        collect_inputs::<Sum>(&patterns, &locations)
+    };
+    let input = names(&["input.txt"]);
+    let example = names(&["example1.txt"]);
+    let globbed = names(&["test*.txt"]);
+    let missing = names(&["example3.txt"]);
+    let defaults = names(&[]);
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(input.unwrap(), [root.join("day00/input.txt")]);
+    assert_eq!(example.unwrap(), [crate_dir.join("test.txt")]);
+    assert_eq!(
+        globbed.unwrap(),
+        [crate_dir.join("test.txt"), crate_dir.join("test2.txt")]
+    );
+    let missing = missing.unwrap_err();
+    assert!(missing.starts_with("Cannot find example3.txt for day 0, searched:"));
+    assert!(missing.contains(&crate_dir.join("test3.txt").display().to_string()));
+    assert_eq!(
+        defaults.unwrap(),
+        [crate_dir.join("test.txt"), root.join("day00/input.txt")]
+    );
+}
+
+#[test]
+fn missing_defaults_are_skipped() {
+    let dir = scratch_dir("defaults");
+    let locations = Inputs::new(Some(dir.join("root")))
+        .unwrap()
+        .with_fallback(&dir);
+    let defaults = collect_inputs::<Sum>(&[], &locations);
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(defaults.unwrap(), Vec::<PathBuf>::new());
+}
+
+#[test]
+fn dash_stands_for_stdin_once() {
+    let dir = scratch_dir("stdin");
+    let locations = Inputs::new(Some(dir.join("root")))
+        .unwrap()
+        .with_fallback(&dir);
+    let patterns = ["-", "a.txt", "-"].map(String::from);
+    let found = collect_inputs::<Sum>(&patterns, &locations);
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(found.unwrap(), [PathBuf::from("-"), dir.join("a.txt")]);
+}
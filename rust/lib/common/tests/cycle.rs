@@ -0,0 +1,113 @@
+use aoc_common::cycle::{self, Cycle};
+
+fn square_plus_one(x: &u64) -> u64 {
+    (x * x + 1) % 255
+}
+
+/// The cycle found by remembering the index of every state, the slow way.
+fn naive(start: u64, step: fn(&u64) -> u64) -> Cycle {
+    let mut states = vec![start];
+    loop {
+        let next = step(states.last().unwrap());
+        if let Some(prefix) = states.iter().position(|&s| s == next) {
+            return Cycle {
+                prefix,
+                length: states.len() - prefix,
+            };
+        }
+        states.push(next);
+    }
+}
+
+#[test]
+fn all_detectors_agree() {
+    for start in 0..255 {
+        let expected = naive(start, square_plus_one);
+        assert_eq!(
+            cycle::floyd(start, square_plus_one),
+            expected,
+            "start {start}"
+        );
+        assert_eq!(
+            cycle::brent(start, square_plus_one),
+            expected,
+            "start {start}"
+        );
+        let (found, states) = cycle::hashed(start, square_plus_one);
+        assert_eq!(found, expected, "start {start}");
+        assert_eq!(states.len(), expected.prefix + expected.length);
+    }
+}
+
+#[test]
+fn pure_cycles_and_fixed_points() {
+    let rotate = |x: &u8| (x + 1) % 7;
+    assert_eq!(
+        cycle::brent(3, rotate),
+        Cycle {
+            prefix: 0,
+            length: 7
+        }
+    );
+    assert_eq!(
+        cycle::floyd(3, rotate),
+        Cycle {
+            prefix: 0,
+            length: 7
+        }
+    );
+
+    let halve = |x: &u32| x / 2;
+    assert_eq!(
+        cycle::hashed(40, halve).0,
+        Cycle {
+            prefix: 6,
+            length: 1
+        }
+    );
+    assert_eq!(
+        cycle::floyd(40, halve),
+        Cycle {
+            prefix: 6,
+            length: 1
+        }
+    );
+    assert_eq!(
+        cycle::brent(40, halve),
+        Cycle {
+            prefix: 6,
+            length: 1
+        }
+    );
+}
+
+#[test]
+fn nth_state_skips_ahead() {
+    for start in [0, 2, 17, 200] {
+        let mut state = start;
+        for n in 0..600 {
+            assert_eq!(cycle::nth_state(start, square_plus_one, n), state);
+            state = square_plus_one(&state);
+        }
+
+        let found = naive(start, square_plus_one);
+        let n = 1_000_000_000_000;
+        let (_, states) = cycle::hashed(start, square_plus_one);
+        assert_eq!(
+            cycle::nth_state(start, square_plus_one, n),
+            states[found.reduce(n)]
+        );
+    }
+}
+
+#[test]
+fn reduce_maps_into_the_first_loop() {
+    let cycle = Cycle {
+        prefix: 3,
+        length: 4,
+    };
+    assert_eq!(cycle.reduce(2), 2);
+    assert_eq!(cycle.reduce(3), 3);
+    assert_eq!(cycle.reduce(7), 3);
+    assert_eq!(cycle.reduce(1_000_000_002), 3 + 999_999_999 % 4);
+}
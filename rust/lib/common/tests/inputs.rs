@@ -0,0 +1,63 @@
+use aoc_common::inputs::{is_path, InputError, Inputs};
+use std::fs;
+use std::path::PathBuf;
+
+#[test]
+fn candidates_search_root_then_crate_then_current_directory() {
+    let inputs = Inputs::new(Some(PathBuf::from("/inputs")))
+        .unwrap()
+        .with_fallback("/crate");
+
+    assert_eq!(
+        inputs.candidates(7, "example2.txt"),
+        [
+            PathBuf::from("/inputs/day07/example2.txt"),
+            PathBuf::from("/crate/example2.txt"),
+            PathBuf::from("/crate/test2.txt"),
+            PathBuf::from("example2.txt"),
+        ]
+    );
+    assert_eq!(
+        inputs.candidates(17, "input.txt"),
+        [
+            PathBuf::from("/inputs/day17/input.txt"),
+            PathBuf::from("/crate/input.txt"),
+            PathBuf::from("input.txt"),
+        ]
+    );
+}
+
+#[test]
+fn find_prefers_root_and_falls_back_to_legacy_names() {
+    let root = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+    let crate_dir = root.join("crate");
+    fs::create_dir_all(root.join("day04")).unwrap();
+    fs::create_dir_all(&crate_dir).unwrap();
+    fs::write(root.join("day04/input.txt"), "").unwrap();
+    fs::write(crate_dir.join("input.txt"), "").unwrap();
+    fs::write(crate_dir.join("test.txt"), "").unwrap();
+
+    let inputs = Inputs::new(Some(root.clone()))
+        .unwrap()
+        .with_fallback(&crate_dir);
+    let input = inputs.find(4, "input.txt").unwrap();
+    let example = inputs.find(4, "example1.txt").unwrap();
+    let missing = inputs.find(4, "example3.txt");
+    fs::remove_dir_all(&root).unwrap();
+
+    assert_eq!(input, root.join("day04/input.txt"));
+    assert_eq!(example, crate_dir.join("test.txt"));
+    match missing {
+        Err(InputError::NotFound { searched, .. }) => assert_eq!(searched.len(), 4),
+        other => panic!("expected NotFound, got {:?}", other),
+    }
+}
+
+#[test]
+fn paths_are_told_apart_from_names() {
+    assert!(!is_path("input.txt"));
+    assert!(!is_path("example*.txt"));
+    assert!(is_path("./input.txt"));
+    assert!(is_path("aoc2023-day9/test.txt"));
+    assert!(is_path("/tmp/input.txt"));
+}
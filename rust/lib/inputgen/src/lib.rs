@@ -0,0 +1,362 @@
+//! Seeded generators of large, valid puzzle inputs for benchmarking.
+//!
+//! The same seed and size always give the same input, so timings are comparable between runs.
+
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::fmt::Write;
+
+fn rng(seed: u64) -> ChaCha8Rng {
+    ChaCha8Rng::seed_from_u64(seed)
+}
+
+/// `cards` scratchcards with 10 winning numbers and 25 numbers each.
+///
+/// Few cards win, and then only a little, so the number of copies stays bounded as on real inputs.
+pub fn day4(seed: u64, cards: usize) -> String {
+    let mut rng = rng(seed);
+    let mut pool: Vec<u32> = (1..100).collect();
+    let mut input = String::new();
+    for card in 1..=cards {
+        let wins = *[0, 0, 0, 0, 0, 0, 1, 1, 2, 3].choose(&mut rng).unwrap();
+        pool.shuffle(&mut rng);
+        let (winning, rest) = pool.split_at(10);
+        let mut numbers: Vec<u32> = winning[..wins].to_vec();
+        numbers.extend(&rest[..25 - wins]);
+        numbers.shuffle(&mut rng);
+        let join = |numbers: &[u32]| {
+            numbers
+                .iter()
+                .map(|n| format!("{:>2}", n))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        writeln!(
+            input,
+            "Card {}: {} | {}",
+            card,
+            join(winning),
+            join(&numbers)
+        )
+        .unwrap();
+    }
+    input
+}
+
+/// `races` races lasting up to 100 ms, each with a beatable record.
+pub fn day6(seed: u64, races: usize) -> String {
+    let mut rng = rng(seed);
+    let times: Vec<u64> = (0..races).map(|_| rng.gen_range(10..100)).collect();
+    let distances: Vec<u64> = times.iter().map(|&t| rng.gen_range(0..t * t / 4)).collect();
+    let join = |values: &[u64]| {
+        values
+            .iter()
+            .map(|v| format!("{:>4}", v))
+            .collect::<String>()
+    };
+    format!("Time:    {}\nDistance:{}\n", join(&times), join(&distances))
+}
+
+/// `hands` random camel card hands with bids.
+pub fn day7(seed: u64, hands: usize) -> String {
+    const CARDS: &[u8] = b"23456789TJQKA";
+    let mut rng = rng(seed);
+    let mut input = String::new();
+    for _ in 0..hands {
+        let hand: String = (0..5)
+            .map(|_| *CARDS.choose(&mut rng).unwrap() as char)
+            .collect();
+        writeln!(input, "{} {}", hand, rng.gen_range(1..1000)).unwrap();
+    }
+    input
+}
+
+/// A network of `ghosts` disjoint rings of 402 to 577 nodes with `instructions` L/R steps.
+///
+/// Both choices of every node lead to the same next node and each `..Z` node leads back to the
+/// successor of its ring's `..A` node, so every ghost reaches its `..Z` node periodically.
+pub fn day8(seed: u64, ghosts: usize, instructions: usize) -> String {
+    const LETTERS: &[u8] = b"BCDEFGHIJKLMNOPQRSTUVWXY";
+    assert!(ghosts <= 10, "ring prefixes are A and digits 1-9");
+    let mut rng = rng(seed);
+    let mut input: String = (0..instructions)
+        .map(|_| if rng.gen_bool(0.5) { 'L' } else { 'R' })
+        .collect();
+    input.push_str("\n\n");
+    for ghost in 0..ghosts {
+        let prefix = if ghost == 0 {
+            'A'
+        } else {
+            (b'0' + ghost as u8) as char
+        };
+        let node = |i: usize| {
+            format!(
+                "{}{}{}",
+                prefix,
+                LETTERS[i / LETTERS.len()] as char,
+                LETTERS[i % LETTERS.len()] as char
+            )
+        };
+        let (start, end) = match ghost {
+            0 => ("AAA".to_string(), "ZZZ".to_string()),
+            _ => (format!("{}AA", prefix), format!("{}ZZ", prefix)),
+        };
+        let length = rng.gen_range(400..LETTERS.len() * LETTERS.len());
+        let mut ring = vec![start];
+        ring.extend((0..length).map(node));
+        ring.push(end);
+        for (i, source) in ring.iter().enumerate() {
+            let next = if i + 1 < ring.len() {
+                &ring[i + 1]
+            } else {
+                &ring[1]
+            };
+            writeln!(input, "{} = ({}, {})", source, next, next).unwrap();
+        }
+    }
+    input
+}
+
+/// `sequences` values of random polynomials of degree up to 5, 21 values each.
+pub fn day9(seed: u64, sequences: usize) -> String {
+    let mut rng = rng(seed);
+    let mut input = String::new();
+    for _ in 0..sequences {
+        let coefficients: Vec<i64> = (0..rng.gen_range(1..=6))
+            .map(|_| rng.gen_range(-9..=9))
+            .collect();
+        let values: Vec<String> = (0..21i64)
+            .map(|x| {
+                coefficients
+                    .iter()
+                    .rev()
+                    .fold(0, |acc, c| acc * x + c)
+                    .to_string()
+            })
+            .collect();
+        writeln!(input, "{}", values.join(" ")).unwrap();
+    }
+    input
+}
+
+/// A `size`×`size` field with a loop along its border and random, disconnected pipes inside.
+pub fn day10(seed: u64, size: usize) -> String {
+    const PIPES: &[u8] = b"|-LJ7F.";
+    assert!(size >= 3, "the loop needs an inside");
+    let mut rng = rng(seed);
+    let mut input = String::new();
+    input.push('S');
+    input.extend(std::iter::repeat_n('-', size - 2));
+    input.push_str("7\n");
+    for _ in 2..size {
+        input.push('|');
+        input.extend((2..size).map(|_| *PIPES.choose(&mut rng).unwrap() as char));
+        input.push_str("|\n");
+    }
+    input.push('L');
+    input.extend(std::iter::repeat_n('-', size - 2));
+    input.push_str("J\n");
+    input
+}
+
+/// A `size`×`size` image with a galaxy in roughly one cell out of `sparsity`.
+///
+/// Some rows and columns are left empty so that expansion has work to do.
+pub fn day11(seed: u64, size: usize, sparsity: u32) -> String {
+    let mut rng = rng(seed);
+    let empty_columns: Vec<bool> = (0..size).map(|_| rng.gen_bool(0.1)).collect();
+    let mut input = String::new();
+    for _ in 0..size {
+        let empty_row = rng.gen_bool(0.1);
+        for &empty_column in &empty_columns {
+            let galaxy = !empty_row && !empty_column && rng.gen_ratio(1, sparsity);
+            input.push(if galaxy { '#' } else { '.' });
+        }
+        input.push('\n');
+    }
+    input
+}
+
+/// `records` spring records of up to 20 springs, each with at least one arrangement.
+pub fn day12(seed: u64, records: usize) -> String {
+    let mut rng = rng(seed);
+    let mut input = String::new();
+    for _ in 0..records {
+        let length = rng.gen_range(8..=20);
+        let mut springs = String::new();
+        let mut groups = Vec::new();
+        while springs.len() < length {
+            if rng.gen_bool(0.4) {
+                springs.push('.');
+                continue;
+            }
+            let group = rng.gen_range(1..=(length - springs.len()).min(5));
+            springs.extend(std::iter::repeat_n('#', group));
+            springs.push('.');
+            groups.push(group.to_string());
+        }
+        if groups.is_empty() {
+            springs.replace_range(0..1, "#");
+            groups.push("1".to_string());
+        }
+        let springs: String = springs
+            .chars()
+            .take(length)
+            .map(|c| if rng.gen_bool(0.4) { '?' } else { c })
+            .collect();
+        writeln!(input, "{} {}", springs, groups.join(",")).unwrap();
+    }
+    input
+}
+
+/// `steps` comma-separated initialisation steps over a few hundred labels.
+pub fn day15(seed: u64, steps: usize) -> String {
+    let mut rng = rng(seed);
+    let labels: Vec<String> = (0..500)
+        .map(|_| {
+            (0..rng.gen_range(2..=6))
+                .map(|_| rng.gen_range(b'a'..=b'z') as char)
+                .collect()
+        })
+        .collect();
+    let steps: Vec<String> = (0..steps)
+        .map(|_| {
+            let label = labels.choose(&mut rng).unwrap();
+            if rng.gen_bool(0.7) {
+                format!("{}={}", label, rng.gen_range(1..=9))
+            } else {
+                format!("{}-", label)
+            }
+        })
+        .collect();
+    steps.join(",") + "\n"
+}
+
+/// A `size`×`size` contraption where about one tile in ten is a mirror or splitter.
+pub fn day16(seed: u64, size: usize) -> String {
+    const DEVICES: &[u8] = b"/\\-|";
+    let mut rng = rng(seed);
+    let mut input = String::new();
+    for _ in 0..size {
+        for _ in 0..size {
+            input.push(if rng.gen_bool(0.1) {
+                *DEVICES.choose(&mut rng).unwrap() as char
+            } else {
+                '.'
+            });
+        }
+        input.push('\n');
+    }
+    input
+}
+
+/// A `width`×`height` map of heat loss digits 1-9.
+pub fn day17(seed: u64, width: usize, height: usize) -> String {
+    let mut rng = rng(seed);
+    let mut input = String::new();
+    for _ in 0..height {
+        input.extend((0..width).map(|_| (b'0' + rng.gen_range(1..=9)) as char));
+        input.push('\n');
+    }
+    input
+}
+
+/// A simple lagoon outline of about `2 * steps` instructions.
+///
+/// The top edge is a random staircase kept above a flat bottom edge, so the outline never crosses
+/// itself. The colours encode the same outline, scaled up as far as five hex digits allow.
+pub fn day18(seed: u64, steps: usize) -> String {
+    const DEPTH: i64 = 500;
+    let mut rng = rng(seed);
+    let mut outline = Vec::new();
+    let (mut x, mut y) = (0, 0);
+    for _ in 0..steps {
+        let distance = rng.gen_range(1..20);
+        outline.push(('R', distance));
+        x += distance;
+        let target = rng.gen_range(0..DEPTH);
+        match target.cmp(&y) {
+            std::cmp::Ordering::Greater => outline.push(('D', target - y)),
+            std::cmp::Ordering::Less => outline.push(('U', y - target)),
+            std::cmp::Ordering::Equal => {}
+        }
+        y = target;
+    }
+    outline.extend([
+        ('R', 1),
+        ('D', DEPTH + 1 - y),
+        ('L', x + 1),
+        ('U', DEPTH + 1),
+    ]);
+
+    let longest = outline.iter().map(|&(_, distance)| distance).max().unwrap();
+    let scale = (0xfffff / longest).max(1);
+    let mut input = String::new();
+    for (direction, distance) in outline {
+        let code = "RDLU".find(direction).unwrap();
+        writeln!(
+            input,
+            "{} {} (#{:05x}{})",
+            direction,
+            distance,
+            distance * scale,
+            code
+        )
+        .unwrap();
+    }
+    input
+}
+
+/// A tree of `workflows` workflows rooted at `in` and `parts` parts to sort.
+pub fn day19(seed: u64, workflows: usize, parts: usize) -> String {
+    const CATEGORIES: &[char] = &['x', 'm', 'a', 's'];
+    let mut rng = rng(seed);
+    let name = |i: usize| match i {
+        0 => "in".to_string(),
+        _ => {
+            let mut name = String::from("w");
+            let mut i = i;
+            while i > 0 {
+                name.push((b'a' + (i % 26) as u8) as char);
+                i /= 26;
+            }
+            name
+        }
+    };
+    let mut next = 1;
+    let mut input = String::new();
+    for i in 0..workflows {
+        let mut target = |rng: &mut ChaCha8Rng| {
+            if next < workflows && rng.gen_bool(0.8) {
+                next += 1;
+                name(next - 1)
+            } else if rng.gen_bool(0.5) {
+                "A".to_string()
+            } else {
+                "R".to_string()
+            }
+        };
+        let mut rules = Vec::new();
+        for _ in 0..rng.gen_range(1..=3) {
+            let category = CATEGORIES.choose(&mut rng).unwrap();
+            let comparison = if rng.gen_bool(0.5) { '<' } else { '>' };
+            let value = rng.gen_range(1..4000);
+            rules.push(format!(
+                "{}{}{}:{}",
+                category,
+                comparison,
+                value,
+                target(&mut rng)
+            ));
+        }
+        rules.push(target(&mut rng));
+        writeln!(input, "{}{{{}}}", name(i), rules.join(",")).unwrap();
+    }
+    input.push('\n');
+    for _ in 0..parts {
+        let [x, m, a, s] = [(); 4].map(|_| rng.gen_range(1..=4000));
+        writeln!(input, "{{x={},m={},a={},s={}}}", x, m, a, s).unwrap();
+    }
+    input
+}
@@ -0,0 +1,35 @@
+use aoc_inputgen::{day16, day17, day18};
+
+#[test]
+fn same_seed_gives_same_input() {
+    assert_eq!(day17(7, 40, 30), day17(7, 40, 30));
+    assert_ne!(day17(7, 40, 30), day17(8, 40, 30));
+}
+
+#[test]
+fn grids_have_requested_size() {
+    let grid = day16(1, 50);
+    assert_eq!(grid.lines().count(), 50);
+    assert!(grid.lines().all(|line| line.len() == 50));
+
+    let grid = day17(1, 40, 30);
+    assert_eq!(grid.lines().count(), 30);
+    assert!(grid.lines().all(|line| line.len() == 40));
+}
+
+#[test]
+fn lagoon_outline_is_closed() {
+    let (mut x, mut y) = (0i64, 0i64);
+    for line in day18(3, 200).lines() {
+        let mut parts = line.split(' ');
+        let direction = parts.next().unwrap();
+        let distance: i64 = parts.next().unwrap().parse().unwrap();
+        match direction {
+            "R" => x += distance,
+            "L" => x -= distance,
+            "D" => y += distance,
+            _ => y -= distance,
+        }
+    }
+    assert_eq!((x, y), (0, 0));
+}
@@ -0,0 +1,239 @@
+use crate::Vec2D;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// One of the four grid directions, with north pointing to lower `y`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+/// A change of heading relative to the current one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Straight,
+    Right,
+    Back,
+    Left,
+}
+
+/// One of the eight compass points, for puzzles that also move diagonally.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Compass {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseDirectionError(pub String);
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown direction '{}'", self.0)
+    }
+}
+
+impl std::error::Error for ParseDirectionError {}
+
+impl Direction {
+    /// All directions, clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Direction> {
+        Direction::ALL.into_iter()
+    }
+
+    fn rotate(self, quarters: usize) -> Direction {
+        Direction::ALL[(self as usize + quarters) % 4]
+    }
+
+    pub fn turn_right(self) -> Direction {
+        self.rotate(1)
+    }
+
+    pub fn reverse(self) -> Direction {
+        self.rotate(2)
+    }
+
+    pub fn turn_left(self) -> Direction {
+        self.rotate(3)
+    }
+
+    pub fn turn(self, turn: Turn) -> Direction {
+        self.rotate(turn as usize)
+    }
+
+    /// The turn that takes this heading to `other`.
+    pub fn turn_to(self, other: Direction) -> Turn {
+        [Turn::Straight, Turn::Right, Turn::Back, Turn::Left]
+            [(other as usize + 4 - self as usize) % 4]
+    }
+
+    pub fn is_perpendicular(self, other: Direction) -> bool {
+        (self as usize + other as usize) % 2 == 1
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::East | Direction::West)
+    }
+
+    /// Unit step in this direction.
+    pub fn to_vec2d(self) -> Vec2D {
+        match self {
+            Direction::North => Vec2D { x: 0, y: -1 },
+            Direction::East => Vec2D { x: 1, y: 0 },
+            Direction::South => Vec2D { x: 0, y: 1 },
+            Direction::West => Vec2D { x: -1, y: 0 },
+        }
+    }
+
+    /// The direction of a unit step, if `step` is one.
+    pub fn from_vec2d(step: Vec2D) -> Option<Direction> {
+        Direction::iter().find(|d| d.to_vec2d() == step)
+    }
+
+    /// Parses `U`, `R`, `D` or `L`.
+    pub fn from_letter(c: char) -> Result<Direction, ParseDirectionError> {
+        match c {
+            'U' => Ok(Direction::North),
+            'R' => Ok(Direction::East),
+            'D' => Ok(Direction::South),
+            'L' => Ok(Direction::West),
+            _ => Err(ParseDirectionError(c.to_string())),
+        }
+    }
+
+    /// Parses `N`, `E`, `S` or `W`.
+    pub fn from_compass(c: char) -> Result<Direction, ParseDirectionError> {
+        match c {
+            'N' => Ok(Direction::North),
+            'E' => Ok(Direction::East),
+            'S' => Ok(Direction::South),
+            'W' => Ok(Direction::West),
+            _ => Err(ParseDirectionError(c.to_string())),
+        }
+    }
+
+    /// Parses the digit codes `0` to `3`, for right, down, left and up.
+    pub fn from_digit(c: char) -> Result<Direction, ParseDirectionError> {
+        match c {
+            '0' => Ok(Direction::East),
+            '1' => Ok(Direction::South),
+            '2' => Ok(Direction::West),
+            '3' => Ok(Direction::North),
+            _ => Err(ParseDirectionError(c.to_string())),
+        }
+    }
+}
+
+impl From<Direction> for Vec2D {
+    fn from(direction: Direction) -> Vec2D {
+        direction.to_vec2d()
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    /// Accepts any of the notations, see [`Direction::from_letter`], [`Direction::from_compass`]
+    /// and [`Direction::from_digit`] to allow only one.
+    fn try_from(c: char) -> Result<Direction, ParseDirectionError> {
+        Direction::from_letter(c)
+            .or_else(|_| Direction::from_compass(c))
+            .or_else(|_| Direction::from_digit(c))
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Direction, ParseDirectionError> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c),
+            _ => Err(ParseDirectionError(s.to_string())),
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let letter = match self {
+            Direction::North => 'N',
+            Direction::East => 'E',
+            Direction::South => 'S',
+            Direction::West => 'W',
+        };
+        write!(f, "{}", letter)
+    }
+}
+
+impl Compass {
+    /// All compass points, clockwise from north.
+    pub const ALL: [Compass; 8] = [
+        Compass::North,
+        Compass::NorthEast,
+        Compass::East,
+        Compass::SouthEast,
+        Compass::South,
+        Compass::SouthWest,
+        Compass::West,
+        Compass::NorthWest,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Compass> {
+        Compass::ALL.into_iter()
+    }
+
+    fn rotate(self, eighths: usize) -> Compass {
+        Compass::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// The next point clockwise, 45 degrees to the right.
+    pub fn turn_right(self) -> Compass {
+        self.rotate(1)
+    }
+
+    pub fn reverse(self) -> Compass {
+        self.rotate(4)
+    }
+
+    /// The next point anticlockwise, 45 degrees to the left.
+    pub fn turn_left(self) -> Compass {
+        self.rotate(7)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    pub fn to_vec2d(self) -> Vec2D {
+        Vec2D::SURROUNDING[self as usize]
+    }
+}
+
+impl From<Direction> for Compass {
+    fn from(direction: Direction) -> Compass {
+        Compass::ALL[direction as usize * 2]
+    }
+}
+
+impl From<Compass> for Vec2D {
+    fn from(compass: Compass) -> Vec2D {
+        compass.to_vec2d()
+    }
+}
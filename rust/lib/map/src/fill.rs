@@ -0,0 +1,96 @@
+use crate::{Map, Vec2D};
+use std::collections::VecDeque;
+
+/// Connected regions of a map, see [`Map::components`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Components {
+    /// Label of the region each cell belongs to, numbered from 0 in row-major order of discovery.
+    pub labels: Map<usize>,
+    /// Number of cells in each region, indexed by label.
+    pub sizes: Vec<usize>,
+}
+
+impl<T> Map<T> {
+    /// Every passable cell reachable from `seed` through edge-adjacent passable cells, in
+    /// breadth-first order. Empty if `seed` itself is off the map or not passable.
+    pub fn flood_fill(&self, seed: Vec2D, passable: impl Fn(&T) -> bool) -> Vec<Vec2D> {
+        self.flood_from([seed], passable)
+    }
+
+    /// Like [`Map::flood_fill`], growing from several seeds at once.
+    pub fn flood_from(
+        &self,
+        seeds: impl IntoIterator<Item = Vec2D>,
+        passable: impl Fn(&T) -> bool,
+    ) -> Vec<Vec2D> {
+        let mut reached = vec![false; self.width * self.height];
+        let mut region = Vec::new();
+        let mut queue = VecDeque::new();
+        let mut visit = |position: Vec2D, queue: &mut VecDeque<Vec2D>| match self.index_of(position)
+        {
+            Some(index) if !reached[index] && passable(&self.data[index]) => {
+                reached[index] = true;
+                queue.push_back(position);
+            }
+            _ => {}
+        };
+        for seed in seeds {
+            visit(seed, &mut queue);
+        }
+        while let Some(position) = queue.pop_front() {
+            region.push(position);
+            for offset in Vec2D::ORTHOGONAL {
+                visit(position + offset, &mut queue);
+            }
+        }
+        region
+    }
+
+    /// Passable cells connected to the edge of the map, leaving whatever is enclosed untouched.
+    pub fn fill_outside(&self, passable: impl Fn(&T) -> bool) -> Vec<Vec2D> {
+        let (width, height) = (self.width as i64, self.height as i64);
+        let border = (0..width)
+            .flat_map(|x| [Vec2D { x, y: 0 }, Vec2D { x, y: height - 1 }])
+            .chain((0..height).flat_map(|y| [Vec2D { x: 0, y }, Vec2D { x: width - 1, y }]));
+        self.flood_from(border, passable)
+    }
+
+    /// Labels regions of edge-adjacent cells, joining neighbours `a` and `b` when
+    /// `connected(a, b)`; for regions of equal cells pass `|a, b| a == b`.
+    pub fn components(&self, connected: impl Fn(&T, &T) -> bool) -> Components {
+        const UNLABELLED: usize = usize::MAX;
+        let mut labels = vec![UNLABELLED; self.data.len()];
+        let mut sizes = Vec::new();
+        let mut queue = VecDeque::new();
+        for start in 0..self.data.len() {
+            if labels[start] != UNLABELLED {
+                continue;
+            }
+            let label = sizes.len();
+            labels[start] = label;
+            queue.push_back(start);
+            let mut size = 0;
+            while let Some(index) = queue.pop_front() {
+                size += 1;
+                let position = self.coords(index);
+                for offset in Vec2D::ORTHOGONAL {
+                    match self.index_of(position + offset) {
+                        Some(next)
+                            if labels[next] == UNLABELLED
+                                && connected(&self.data[index], &self.data[next]) =>
+                        {
+                            labels[next] = label;
+                            queue.push_back(next);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            sizes.push(size);
+        }
+        Components {
+            labels: Map::new(self.height, self.width, labels),
+            sizes,
+        }
+    }
+}
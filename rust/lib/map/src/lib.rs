@@ -1,55 +1,54 @@
-use core::ops::{Add, Mul, Sub};
+use aoc_common::{ParsingError, Position};
+use core::ops::{Index, IndexMut};
 use std::fmt::Debug;
-use std::fmt::Write;
-use std::slice::Chunks;
+use std::fmt::{Display, Write};
+use std::slice::{Chunks, ChunksMut};
+use std::str::FromStr;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
-pub struct Vec2D {
-    pub x: i64,
-    pub y: i64,
-}
+mod direction;
+mod fill;
+mod map3;
+mod polygon;
+pub mod render;
+pub mod search;
+mod sparse;
+mod vec2;
+mod vec3;
+mod vector;
 
-pub struct Map<T> {
-    pub height: usize,
-    pub width: usize,
-    data: Vec<T>,
-}
+pub use direction::{Compass, Direction, ParseDirectionError, Turn};
+pub use fill::Components;
+pub use map3::{Map3, OutOfBounds3};
+pub use polygon::{Orientation, Polygon};
+pub use sparse::SparseMap;
+pub use vec2::{Vec2, Vec2D};
+pub use vec3::{Vec3, Vec3D};
 
-impl Add for Vec2D {
-    type Output = Vec2D;
-    fn add(self, rhs: Vec2D) -> Vec2D {
-        Vec2D {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
-    }
+/// A position that does not lie on the map it was used with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OutOfBounds {
+    pub position: Vec2D,
+    pub width: usize,
+    pub height: usize,
 }
 
-impl Mul<i64> for Vec2D {
-    type Output = Vec2D;
-    fn mul(self, rhs: i64) -> Vec2D {
-        Vec2D {
-            x: self.x * rhs,
-            y: self.y * rhs,
-        }
+impl Display for OutOfBounds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "position {} is outside the {}x{} map",
+            self.position, self.width, self.height
+        )
     }
 }
 
-impl Mul<Vec2D> for Vec2D {
-    type Output = i64;
-    fn mul(self, rhs: Vec2D) -> i64 {
-        self.x * rhs.x + self.y * rhs.y
-    }
-}
+impl std::error::Error for OutOfBounds {}
 
-impl Sub for Vec2D {
-    type Output = Vec2D;
-    fn sub(self, rhs: Vec2D) -> Vec2D {
-        Vec2D {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-        }
-    }
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Map<T> {
+    pub height: usize,
+    pub width: usize,
+    data: Vec<T>,
 }
 
 impl<T> Map<T> {
@@ -61,71 +60,314 @@ impl<T> Map<T> {
         }
     }
 
+    /// Parses a grid with one row per line, converting each character with `cell`.
+    ///
+    /// Every row must be as wide as the first; otherwise the first ragged row is reported.
+    pub fn parse_with<E: Display>(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, E>,
+    ) -> aoc_common::Result<Map<T>> {
+        let mut data = Vec::with_capacity(input.len());
+        let mut width = None;
+        let mut height = 0;
+        for (index, line) in input.lines().enumerate() {
+            let start = data.len();
+            for (column, c) in line.chars().enumerate() {
+                let value = cell(c).map_err(|e| ParsingError::InvalidCell {
+                    position: Position {
+                        line: index + 1,
+                        column: column + 1,
+                    },
+                    cell: c,
+                    reason: e.to_string(),
+                })?;
+                data.push(value);
+            }
+            let found = data.len() - start;
+            let expected = *width.get_or_insert(found);
+            if found != expected {
+                return Err(ParsingError::MalformedGrid {
+                    line: index + 1,
+                    expected,
+                    found,
+                });
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Map::new(height, width, data)),
+            _ => Err(ParsingError::Missing("grid")),
+        }
+    }
+
+    /// The cells in row-major order.
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
     pub fn column_iter(&self, column_index: usize) -> impl Iterator<Item = &T> {
-        self.data.iter().skip(column_index).step_by(self.height)
+        self.column(column_index)
     }
 
-    pub fn rows(&self) -> Chunks<T> {
+    pub fn rows(&self) -> Chunks<'_, T> {
         self.data.chunks(self.width)
     }
 
+    pub fn rows_mut(&mut self) -> ChunksMut<'_, T> {
+        self.data.chunks_mut(self.width)
+    }
+
+    /// Each column, top to bottom, from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|column_index| self.column(column_index))
+    }
+
+    /// Row `row_index`, left to right. Panics if it is outside the map.
+    pub fn row(&self, row_index: usize) -> &[T] {
+        assert!(row_index < self.height, "row {row_index} out of range");
+        &self.data[row_index * self.width..(row_index + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, row_index: usize) -> &mut [T] {
+        assert!(row_index < self.height, "row {row_index} out of range");
+        &mut self.data[row_index * self.width..(row_index + 1) * self.width]
+    }
+
+    /// Column `column_index`, top to bottom. Panics if it is outside the map.
+    pub fn column(&self, column_index: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            column_index < self.width,
+            "column {column_index} out of range"
+        );
+        self.data.iter().skip(column_index).step_by(self.width)
+    }
+
+    pub fn column_mut(&mut self, column_index: usize) -> impl Iterator<Item = &mut T> {
+        assert!(
+            column_index < self.width,
+            "column {column_index} out of range"
+        );
+        self.data.iter_mut().skip(column_index).step_by(self.width)
+    }
+
     pub fn coords(&self, index: usize) -> Vec2D {
         let x = index % self.width;
-        let y = index / self.height;
+        let y = index / self.width;
         Vec2D {
             x: x as i64,
             y: y as i64,
         }
     }
 
-    pub fn get_mut(&mut self, position: Vec2D) -> Option<&mut T> {
-        if position.x < 0
-            || position.y < 0
-            || position.x as usize >= self.width
-            || position.y as usize >= self.height
-        {
-            None
-        } else {
-            let index: usize = position.y as usize * self.width + position.x as usize;
-            Some(self.data.get_mut(index).unwrap())
+    /// Mirrors the map left to right in place.
+    pub fn flip_h_mut(&mut self) {
+        self.rows_mut().for_each(|row| row.reverse());
+    }
+
+    /// Mirrors the map top to bottom in place.
+    pub fn flip_v_mut(&mut self) {
+        for top in 0..self.height / 2 {
+            let (upper, lower) = self.data.split_at_mut((self.height - top - 1) * self.width);
+            upper[top * self.width..(top + 1) * self.width]
+                .swap_with_slice(&mut lower[..self.width]);
         }
     }
 
+    pub fn contains(&self, position: Vec2D) -> bool {
+        (0..self.width as i64).contains(&position.x)
+            && (0..self.height as i64).contains(&position.y)
+    }
+
+    /// Index of `position` in the row-major cells, the inverse of [`Map::coords`].
+    pub fn index_of(&self, position: Vec2D) -> Option<usize> {
+        self.contains(position)
+            .then(|| position.y as usize * self.width + position.x as usize)
+    }
+
+    fn out_of_bounds(&self, position: Vec2D) -> OutOfBounds {
+        OutOfBounds {
+            position,
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Vec2D) -> Option<&mut T> {
+        let index = self.index_of(position)?;
+        Some(&mut self.data[index])
+    }
+
     pub fn get(&self, position: Vec2D) -> Option<&T> {
-        if position.x < 0
-            || position.y < 0
-            || position.x as usize >= self.width
-            || position.y as usize >= self.height
-        {
-            None
-        } else {
-            let index: usize = position.y as usize * self.width + position.x as usize;
-            Some(self.data.get(index).unwrap())
+        let index = self.index_of(position)?;
+        Some(&self.data[index])
+    }
+
+    /// `position` wrapped into the map, as if it tiled the plane.
+    pub fn wrap(&self, position: Vec2D) -> Vec2D {
+        Vec2D {
+            x: position.x.rem_euclid(self.width as i64),
+            y: position.y.rem_euclid(self.height as i64),
         }
     }
 
+    pub fn get_wrapping(&self, position: Vec2D) -> &T {
+        self.get(self.wrap(position)).unwrap()
+    }
+
+    /// Cells at `offsets` from `position` that lie inside the map.
+    pub fn stencil<'a>(
+        &'a self,
+        position: Vec2D,
+        offsets: impl IntoIterator<Item = Vec2D> + 'a,
+    ) -> impl Iterator<Item = (Vec2D, &'a T)> + 'a {
+        offsets.into_iter().filter_map(move |offset| {
+            let neighbour = position + offset;
+            self.get(neighbour).map(|value| (neighbour, value))
+        })
+    }
+
+    /// Cells at `offsets` from `position`, wrapping around the edges; positions are wrapped too.
+    pub fn stencil_wrapping<'a>(
+        &'a self,
+        position: Vec2D,
+        offsets: impl IntoIterator<Item = Vec2D> + 'a,
+    ) -> impl Iterator<Item = (Vec2D, &'a T)> + 'a {
+        offsets.into_iter().map(move |offset| {
+            let neighbour = self.wrap(position + offset);
+            (neighbour, self.get(neighbour).unwrap())
+        })
+    }
+
+    pub fn neighbours4(&self, position: Vec2D) -> impl Iterator<Item = (Vec2D, &T)> + '_ {
+        self.stencil(position, Vec2D::ORTHOGONAL)
+    }
+
+    pub fn neighbours8(&self, position: Vec2D) -> impl Iterator<Item = (Vec2D, &T)> + '_ {
+        self.stencil(position, Vec2D::SURROUNDING)
+    }
+
+    pub fn neighbours4_wrapping(&self, position: Vec2D) -> impl Iterator<Item = (Vec2D, &T)> + '_ {
+        self.stencil_wrapping(position, Vec2D::ORTHOGONAL)
+    }
+
+    pub fn neighbours8_wrapping(&self, position: Vec2D) -> impl Iterator<Item = (Vec2D, &T)> + '_ {
+        self.stencil_wrapping(position, Vec2D::SURROUNDING)
+    }
+
+    /// Overwrites the cell at `position`. Panics if it is outside the map.
     pub fn set(&mut self, position: Vec2D, value: T) {
-        if position.x >= 0 && position.y >= 0 {
-            let index: usize = position.y as usize * self.width + position.x as usize;
-            self.data[index] = value;
-        }
+        self[position] = value;
     }
 
+    pub fn try_set(&mut self, position: Vec2D, value: T) -> Result<(), OutOfBounds> {
+        let index = self
+            .index_of(position)
+            .ok_or_else(|| self.out_of_bounds(position))?;
+        self.data[index] = value;
+        Ok(())
+    }
+
+    /// Applies `f` to the cell at `position`. Panics if it is outside the map.
     pub fn modify(&mut self, position: Vec2D, f: impl Fn(&mut T)) {
-        if position.x >= 0 && position.y >= 0 {
-            let index: usize = position.y as usize * self.width + position.x as usize;
-            f(self.data.get_mut(index).unwrap());
-        }
+        f(&mut self[position]);
+    }
+
+    pub fn try_modify(&mut self, position: Vec2D, f: impl Fn(&mut T)) -> Result<(), OutOfBounds> {
+        let index = self
+            .index_of(position)
+            .ok_or_else(|| self.out_of_bounds(position))?;
+        f(&mut self.data[index]);
+        Ok(())
     }
 
     pub fn print_with(&self, f: impl Fn(&T) -> String) {
         self.data.chunks(self.width).for_each(|row| {
-            println!("");
+            println!();
             row.iter().for_each(|x| {
                 print!("{}\t", f(x));
             });
         });
-        println!("");
+        println!();
+    }
+}
+
+impl<T: Clone> Map<T> {
+    /// A `height` by `width` map whose cell `(x, y)` is this map's cell `source(x, y)`.
+    fn remap(
+        &self,
+        height: usize,
+        width: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Map<T> {
+        let mut data = Vec::with_capacity(self.data.len());
+        for y in 0..height {
+            for x in 0..width {
+                let (from_x, from_y) = source(x, y);
+                data.push(self.data[from_y * self.width + from_x].clone());
+            }
+        }
+        Map::new(height, width, data)
+    }
+
+    /// Swaps rows and columns, mirroring the map along its main diagonal.
+    pub fn transpose(&self) -> Map<T> {
+        self.remap(self.width, self.height, |x, y| (y, x))
+    }
+
+    /// The map turned a quarter clockwise: the left column becomes the top row.
+    pub fn rotate_cw(&self) -> Map<T> {
+        self.remap(self.width, self.height, |x, y| (y, self.height - 1 - x))
+    }
+
+    /// The map turned a quarter anticlockwise: the top row becomes the left column.
+    pub fn rotate_ccw(&self) -> Map<T> {
+        self.remap(self.width, self.height, |x, y| (self.width - 1 - y, x))
+    }
+
+    /// The map mirrored left to right.
+    pub fn flip_h(&self) -> Map<T> {
+        let mut flipped = self.clone();
+        flipped.flip_h_mut();
+        flipped
+    }
+
+    /// The map mirrored top to bottom.
+    pub fn flip_v(&self) -> Map<T> {
+        let mut flipped = self.clone();
+        flipped.flip_v_mut();
+        flipped
+    }
+}
+
+impl<T> Index<Vec2D> for Map<T> {
+    type Output = T;
+
+    fn index(&self, position: Vec2D) -> &T {
+        match self.index_of(position) {
+            Some(index) => &self.data[index],
+            None => panic!("{}", self.out_of_bounds(position)),
+        }
+    }
+}
+
+impl<T> IndexMut<Vec2D> for Map<T> {
+    fn index_mut(&mut self, position: Vec2D) -> &mut T {
+        match self.index_of(position) {
+            Some(index) => &mut self.data[index],
+            None => panic!("{}", self.out_of_bounds(position)),
+        }
+    }
+}
+
+impl<T> FromStr for Map<T>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    type Err = ParsingError;
+
+    fn from_str(input: &str) -> aoc_common::Result<Map<T>> {
+        Map::parse_with(input, T::try_from)
     }
 }
 
@@ -0,0 +1,185 @@
+use crate::{Map, Vec3D};
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+/// A position that does not lie in the [`Map3`] it was used with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OutOfBounds3 {
+    pub position: Vec3D,
+    pub width: usize,
+    pub height: usize,
+    pub depth: usize,
+}
+
+impl Display for OutOfBounds3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "position {} is outside the {}x{}x{} map",
+            self.position, self.width, self.height, self.depth
+        )
+    }
+}
+
+impl std::error::Error for OutOfBounds3 {}
+
+/// Dense voxel grid, stored as `depth` z-layers of `height` rows of `width` cells.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Map3<T> {
+    pub depth: usize,
+    pub height: usize,
+    pub width: usize,
+    data: Vec<T>,
+}
+
+impl<T> Map3<T> {
+    pub fn new(depth: usize, height: usize, width: usize, data: Vec<T>) -> Map3<T> {
+        assert_eq!(
+            data.len(),
+            depth * height * width,
+            "data does not fill the map"
+        );
+        Map3 {
+            depth,
+            height,
+            width,
+            data,
+        }
+    }
+
+    pub fn filled(depth: usize, height: usize, width: usize, value: T) -> Map3<T>
+    where
+        T: Clone,
+    {
+        Map3::new(depth, height, width, vec![value; depth * height * width])
+    }
+
+    /// The cells with x varying fastest, then y, then z.
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    fn layer_size(&self) -> usize {
+        self.width * self.height
+    }
+
+    pub fn contains(&self, position: Vec3D) -> bool {
+        (0..self.width as i64).contains(&position.x)
+            && (0..self.height as i64).contains(&position.y)
+            && (0..self.depth as i64).contains(&position.z)
+    }
+
+    /// Index of `position` in the cells, the inverse of [`Map3::coords`].
+    pub fn index_of(&self, position: Vec3D) -> Option<usize> {
+        self.contains(position).then(|| {
+            position.z as usize * self.layer_size()
+                + position.y as usize * self.width
+                + position.x as usize
+        })
+    }
+
+    pub fn coords(&self, index: usize) -> Vec3D {
+        Vec3D {
+            x: (index % self.width) as i64,
+            y: (index / self.width % self.height) as i64,
+            z: (index / self.layer_size()) as i64,
+        }
+    }
+
+    fn out_of_bounds(&self, position: Vec3D) -> OutOfBounds3 {
+        OutOfBounds3 {
+            position,
+            width: self.width,
+            height: self.height,
+            depth: self.depth,
+        }
+    }
+
+    pub fn get(&self, position: Vec3D) -> Option<&T> {
+        let index = self.index_of(position)?;
+        Some(&self.data[index])
+    }
+
+    pub fn get_mut(&mut self, position: Vec3D) -> Option<&mut T> {
+        let index = self.index_of(position)?;
+        Some(&mut self.data[index])
+    }
+
+    /// Overwrites the cell at `position`. Panics if it is outside the map.
+    pub fn set(&mut self, position: Vec3D, value: T) {
+        self[position] = value;
+    }
+
+    pub fn try_set(&mut self, position: Vec3D, value: T) -> Result<(), OutOfBounds3> {
+        let index = self
+            .index_of(position)
+            .ok_or_else(|| self.out_of_bounds(position))?;
+        self.data[index] = value;
+        Ok(())
+    }
+
+    /// The z-layer `z` in row-major order. Panics if it is outside the map.
+    pub fn layer(&self, z: usize) -> &[T] {
+        assert!(z < self.depth, "layer {z} out of range");
+        &self.data[z * self.layer_size()..(z + 1) * self.layer_size()]
+    }
+
+    pub fn layer_mut(&mut self, z: usize) -> &mut [T] {
+        assert!(z < self.depth, "layer {z} out of range");
+        let size = self.layer_size();
+        &mut self.data[z * size..(z + 1) * size]
+    }
+
+    /// The z-layers from bottom to top.
+    pub fn layers(&self) -> impl Iterator<Item = &[T]> {
+        self.data.chunks(self.layer_size())
+    }
+
+    /// A copy of z-layer `z` as a flat map, e.g. for rendering.
+    pub fn layer_map(&self, z: usize) -> Map<T>
+    where
+        T: Clone,
+    {
+        Map::new(self.height, self.width, self.layer(z).to_vec())
+    }
+
+    /// Cells at `offsets` from `position` that lie inside the map.
+    pub fn stencil<'a>(
+        &'a self,
+        position: Vec3D,
+        offsets: impl IntoIterator<Item = Vec3D> + 'a,
+    ) -> impl Iterator<Item = (Vec3D, &'a T)> + 'a {
+        offsets.into_iter().filter_map(move |offset| {
+            let neighbour = position + offset;
+            self.get(neighbour).map(|value| (neighbour, value))
+        })
+    }
+
+    pub fn neighbours6(&self, position: Vec3D) -> impl Iterator<Item = (Vec3D, &T)> + '_ {
+        self.stencil(position, Vec3D::ORTHOGONAL)
+    }
+
+    pub fn neighbours26(&self, position: Vec3D) -> impl Iterator<Item = (Vec3D, &T)> + '_ {
+        self.stencil(position, Vec3D::SURROUNDING)
+    }
+}
+
+impl<T> Index<Vec3D> for Map3<T> {
+    type Output = T;
+
+    fn index(&self, position: Vec3D) -> &T {
+        match self.index_of(position) {
+            Some(index) => &self.data[index],
+            None => panic!("{}", self.out_of_bounds(position)),
+        }
+    }
+}
+
+impl<T> IndexMut<Vec3D> for Map3<T> {
+    fn index_mut(&mut self, position: Vec3D) -> &mut T {
+        match self.index_of(position) {
+            Some(index) => &mut self.data[index],
+            None => panic!("{}", self.out_of_bounds(position)),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use crate::Vec2D;
+use std::collections::HashSet;
+
+/// Which way the vertices of a polygon run, as seen on the map with `y` pointing down.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// Closed polygon on the integer lattice, with an edge from each vertex to the next and from
+/// the last back to the first.
+///
+/// Areas and point counts are `i128`, as dig plans can span millions of cells each way. The
+/// boundary may touch itself, as long as it does so at a vertex and the enclosed lobes meet from
+/// the outside, like two squares sharing a corner.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Vec2D>,
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Vec2D>) -> Polygon {
+        Polygon { vertices }
+    }
+
+    /// Polygon walked from `start` by each step in turn, which is then not repeated as a last
+    /// vertex; `None` if the walk does not return to `start`.
+    pub fn trace(start: Vec2D, steps: impl IntoIterator<Item = Vec2D>) -> Option<Polygon> {
+        let mut position = start;
+        let mut vertices = vec![start];
+        vertices.extend(steps.into_iter().map(|step| {
+            position += step;
+            position
+        }));
+        if position != start {
+            return None;
+        }
+        if vertices.len() > 1 {
+            vertices.pop();
+        }
+        Some(Polygon { vertices })
+    }
+
+    pub fn vertices(&self) -> &[Vec2D] {
+        &self.vertices
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (Vec2D, Vec2D)> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next.copied())
+    }
+
+    /// Twice the signed area by the shoelace formula, positive when the vertices run clockwise.
+    ///
+    /// Doubled so that it stays exact for polygons with half-integer area.
+    pub fn double_signed_area(&self) -> i128 {
+        self.edges()
+            .map(|(a, b)| a.x as i128 * b.y as i128 - b.x as i128 * a.y as i128)
+            .sum()
+    }
+
+    /// Enclosed area, rounded down for polygons with diagonal edges and a half-integer area.
+    pub fn area(&self) -> i128 {
+        self.double_signed_area().abs() / 2
+    }
+
+    /// `None` for degenerate polygons that enclose no area.
+    pub fn orientation(&self) -> Option<Orientation> {
+        match self.double_signed_area().signum() {
+            1 => Some(Orientation::Clockwise),
+            -1 => Some(Orientation::CounterClockwise),
+            _ => None,
+        }
+    }
+
+    /// Euclidean length of the boundary.
+    pub fn perimeter(&self) -> f64 {
+        self.edges()
+            .map(|(a, b)| ((b.x - a.x) as f64).hypot((b.y - a.y) as f64))
+            .sum()
+    }
+
+    /// Lattice points on the boundary walk, counting a point as often as the walk passes it.
+    fn boundary_steps(&self) -> i128 {
+        self.edges()
+            .map(|(a, b)| gcd((b.x - a.x) as i128, (b.y - a.y) as i128))
+            .sum()
+    }
+
+    /// Distinct lattice points on the boundary.
+    pub fn boundary_points(&self) -> i128 {
+        let distinct: HashSet<&Vec2D> = self.vertices.iter().collect();
+        self.boundary_steps() - (self.vertices.len() - distinct.len()) as i128
+    }
+
+    /// Lattice points strictly inside, by Pick's theorem.
+    pub fn interior_points(&self) -> i128 {
+        self.lattice_points() - self.boundary_points()
+    }
+
+    /// Lattice points inside or on the boundary, e.g. the cells a trench along the boundary and
+    /// the ground it encloses cover.
+    pub fn lattice_points(&self) -> i128 {
+        // Pick's theorem per lobe; lobes touching at a point share it, which the repeated
+        // boundary step accounts for
+        (self.double_signed_area().abs() + self.boundary_steps() + 2) / 2
+    }
+}
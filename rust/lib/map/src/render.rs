@@ -0,0 +1,193 @@
+//! Drawing maps as text, as coloured terminal output or as images.
+//!
+//! A [`Renderer`] turns each cell into a glyph and a colour, then lets [`Overlay`]s repaint
+//! chosen positions, such as a path or a visited set, before writing the result out.
+
+use crate::{Map, Vec2D};
+use std::collections::HashSet;
+use std::io::{self, Write};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const RED: Rgb = Rgb(230, 40, 40);
+    pub const GREEN: Rgb = Rgb(40, 200, 70);
+    pub const BLUE: Rgb = Rgb(50, 100, 230);
+    pub const YELLOW: Rgb = Rgb(250, 210, 40);
+
+    /// Shade of grey, from black at 0 to white at 255.
+    pub fn grey(level: u8) -> Rgb {
+        Rgb(level, level, level)
+    }
+}
+
+/// Positions to highlight, drawn in `colour` and optionally with their own glyph.
+#[derive(Clone, Debug)]
+pub struct Overlay {
+    positions: HashSet<Vec2D>,
+    colour: Rgb,
+    glyph: Option<char>,
+}
+
+impl Overlay {
+    pub fn new(positions: impl IntoIterator<Item = Vec2D>, colour: Rgb) -> Overlay {
+        Overlay {
+            positions: positions.into_iter().collect(),
+            colour,
+            glyph: None,
+        }
+    }
+
+    /// Replaces the glyph of every highlighted cell; without one the cell keeps its own.
+    pub fn with_glyph(self, glyph: char) -> Overlay {
+        Overlay {
+            glyph: Some(glyph),
+            ..self
+        }
+    }
+}
+
+type CellFn<'a, T, R> = Box<dyn Fn(&T) -> R + 'a>;
+
+/// Draws a [`Map`] through a cell to glyph mapping and an optional cell to colour mapping.
+///
+/// Without a colour mapping, blank and `.` cells are black and every other cell is white.
+pub struct Renderer<'a, T> {
+    map: &'a Map<T>,
+    glyph: CellFn<'a, T, char>,
+    colour: Option<CellFn<'a, T, Rgb>>,
+    overlays: Vec<Overlay>,
+    scale: usize,
+}
+
+impl<'a, T> Renderer<'a, T> {
+    pub fn new(map: &'a Map<T>, glyph: impl Fn(&T) -> char + 'a) -> Renderer<'a, T> {
+        Renderer {
+            map,
+            glyph: Box::new(glyph),
+            colour: None,
+            overlays: Vec::new(),
+            scale: 1,
+        }
+    }
+
+    pub fn with_colour(self, colour: impl Fn(&T) -> Rgb + 'a) -> Renderer<'a, T> {
+        Renderer {
+            colour: Some(Box::new(colour)),
+            ..self
+        }
+    }
+
+    /// Adds an overlay; where overlays share a position the last one added wins.
+    pub fn with_overlay(mut self, overlay: Overlay) -> Renderer<'a, T> {
+        self.overlays.push(overlay);
+        self
+    }
+
+    /// Side length in pixels of each cell in image output.
+    pub fn with_scale(self, scale: usize) -> Renderer<'a, T> {
+        assert!(scale > 0, "scale must be at least one pixel");
+        Renderer { scale, ..self }
+    }
+
+    /// Glyph and colour of the cell at `position`, after overlays.
+    fn cell(&self, position: Vec2D, value: &T) -> (char, Rgb) {
+        let overlay = self
+            .overlays
+            .iter()
+            .rev()
+            .find(|overlay| overlay.positions.contains(&position));
+        let glyph = (self.glyph)(value);
+        let colour = match (overlay, &self.colour) {
+            (Some(overlay), _) => overlay.colour,
+            (None, Some(colour)) => colour(value),
+            (None, None) if glyph == '.' || glyph.is_whitespace() => Rgb::BLACK,
+            (None, None) => Rgb::WHITE,
+        };
+        (overlay.and_then(|o| o.glyph).unwrap_or(glyph), colour)
+    }
+
+    fn rows(&self) -> impl Iterator<Item = Vec<(char, Rgb)>> + '_ {
+        self.map.rows().enumerate().map(move |(y, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(x, value)| {
+                    let position = Vec2D {
+                        x: x as i64,
+                        y: y as i64,
+                    };
+                    self.cell(position, value)
+                })
+                .collect()
+        })
+    }
+
+    /// One line of glyphs per row.
+    pub fn write_ascii(&self, out: &mut impl Write) -> io::Result<()> {
+        for row in self.rows() {
+            let line: String = row.into_iter().map(|(glyph, _)| glyph).collect();
+            writeln!(out, "{}", line)?;
+        }
+        Ok(())
+    }
+
+    /// Like [`Renderer::write_ascii`], with each glyph in its 24-bit terminal colour.
+    pub fn write_ansi(&self, out: &mut impl Write) -> io::Result<()> {
+        for row in self.rows() {
+            let mut current = None;
+            for (glyph, colour) in row {
+                if current != Some(colour) {
+                    let Rgb(r, g, b) = colour;
+                    write!(out, "\x1b[38;2;{};{};{}m", r, g, b)?;
+                    current = Some(colour);
+                }
+                write!(out, "{}", glyph)?;
+            }
+            writeln!(out, "\x1b[0m")?;
+        }
+        Ok(())
+    }
+
+    fn pixels(&self) -> Vec<u8> {
+        let mut pixels =
+            Vec::with_capacity(self.map.width * self.map.height * self.scale.pow(2) * 3);
+        for row in self.rows() {
+            let mut line = Vec::with_capacity(row.len() * self.scale * 3);
+            for (_, Rgb(r, g, b)) in row {
+                for _ in 0..self.scale {
+                    line.extend([r, g, b]);
+                }
+            }
+            for _ in 0..self.scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+        pixels
+    }
+
+    fn image_size(&self) -> (usize, usize) {
+        (self.map.width * self.scale, self.map.height * self.scale)
+    }
+
+    /// Binary PPM (`P6`) image, which most image viewers open directly.
+    pub fn write_ppm(&self, out: &mut impl Write) -> io::Result<()> {
+        let (width, height) = self.image_size();
+        write!(out, "P6\n{} {}\n255\n", width, height)?;
+        out.write_all(&self.pixels())
+    }
+
+    pub fn write_png(&self, out: &mut impl Write) -> io::Result<()> {
+        let (width, height) = self.image_size();
+        let mut encoder = png::Encoder::new(out, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer
+            .write_image_data(&self.pixels())
+            .map_err(io::Error::other)?;
+        writer.finish().map_err(io::Error::other)
+    }
+}
@@ -0,0 +1,133 @@
+//! Shortest paths over any state type, driven by a successor function.
+//!
+//! Every search returns the cost of the cheapest path to the first goal state reached together
+//! with the path itself, start and goal included, or `None` if no goal is reachable.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+const NO_PARENT: usize = usize::MAX;
+
+/// States seen so far, each stored once and linked to the state it was reached from.
+struct Visited<S, C> {
+    index: HashMap<S, usize>,
+    states: Vec<(S, C, usize)>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy + Ord> Visited<S, C> {
+    fn new(start: S, cost: C) -> Visited<S, C> {
+        Visited {
+            index: HashMap::from([(start.clone(), 0)]),
+            states: vec![(start, cost, NO_PARENT)],
+        }
+    }
+
+    /// Records `state` if it is new or now cheaper, returning its index.
+    fn improve(&mut self, state: S, cost: C, parent: usize) -> Option<usize> {
+        match self.index.entry(state) {
+            Entry::Occupied(entry) => {
+                let i = *entry.get();
+                if self.states[i].1 <= cost {
+                    return None;
+                }
+                self.states[i].1 = cost;
+                self.states[i].2 = parent;
+                Some(i)
+            }
+            Entry::Vacant(entry) => {
+                let i = self.states.len();
+                self.states.push((entry.key().clone(), cost, parent));
+                entry.insert(i);
+                Some(i)
+            }
+        }
+    }
+
+    fn path(&self, mut i: usize) -> (C, Vec<S>) {
+        let cost = self.states[i].1;
+        let mut path = Vec::new();
+        while i != NO_PARENT {
+            path.push(self.states[i].0.clone());
+            i = self.states[i].2;
+        }
+        path.reverse();
+        (cost, path)
+    }
+}
+
+/// Breadth-first search where every step costs one.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Option<(usize, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = Visited::new(start, 0);
+    let mut queue = VecDeque::from([0]);
+    while let Some(i) = queue.pop_front() {
+        let (state, steps, _) = &visited.states[i];
+        if goal(state) {
+            return Some(visited.path(i));
+        }
+        let steps = steps + 1;
+        for next in successors(state) {
+            if !visited.index.contains_key(&next) {
+                queue.extend(visited.improve(next, steps, i));
+            }
+        }
+    }
+    None
+}
+
+/// Dijkstra's algorithm over non-negative step costs.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<(C, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, goal, |_| C::default())
+}
+
+/// A* search; `heuristic` must never overestimate the remaining cost to a goal.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+    mut heuristic: impl FnMut(&S) -> C,
+) -> Option<(C, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    let mut visited = Visited::new(start, C::default());
+    while let Some(Reverse((_, cost, i))) = queue.pop() {
+        let state = &visited.states[i].0;
+        if cost > visited.states[i].1 {
+            continue; // a cheaper way here was found after this entry was queued
+        }
+        if goal(state) {
+            return Some(visited.path(i));
+        }
+        for (next, step) in successors(state) {
+            let next_cost = cost + step;
+            let estimate = next_cost + heuristic(&next);
+            if let Some(j) = visited.improve(next, next_cost, i) {
+                queue.push(Reverse((estimate, next_cost, j)));
+            }
+        }
+    }
+    None
+}
@@ -0,0 +1,182 @@
+use crate::{Map, Vec2D};
+use std::collections::HashMap;
+
+/// Unbounded grid that only stores the cells that were set, every other cell reads as `default`.
+///
+/// Suits puzzles that walk into negative coordinates or tile the plane, where a dense [`Map`]
+/// would have to guess its size up front.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseMap<T> {
+    cells: HashMap<Vec2D, T>,
+    default: T,
+    bounds: Option<(Vec2D, Vec2D)>,
+}
+
+/// `bounds` stretched to cover `position`.
+fn grow(bounds: Option<(Vec2D, Vec2D)>, position: Vec2D) -> (Vec2D, Vec2D) {
+    match bounds {
+        None => (position, position),
+        Some((min, max)) => (
+            Vec2D {
+                x: min.x.min(position.x),
+                y: min.y.min(position.y),
+            },
+            Vec2D {
+                x: max.x.max(position.x),
+                y: max.y.max(position.y),
+            },
+        ),
+    }
+}
+
+impl<T> SparseMap<T> {
+    pub fn new(default: T) -> SparseMap<T> {
+        SparseMap {
+            cells: HashMap::new(),
+            default,
+            bounds: None,
+        }
+    }
+
+    /// Converts a dense map, keeping only the cells that differ from `default`.
+    ///
+    /// The top-left cell of `map` ends up at `origin`.
+    pub fn from_map(map: Map<T>, origin: Vec2D, default: T) -> SparseMap<T>
+    where
+        T: PartialEq,
+    {
+        let mut sparse = SparseMap::new(default);
+        let width = map.width;
+        for (index, value) in map.into_vec().into_iter().enumerate() {
+            if value != sparse.default {
+                let offset = Vec2D {
+                    x: (index % width) as i64,
+                    y: (index / width) as i64,
+                };
+                sparse.insert(origin + offset, value);
+            }
+        }
+        sparse
+    }
+
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// Value at `position`, or the default if it was never set.
+    pub fn get(&self, position: Vec2D) -> &T {
+        self.cells.get(&position).unwrap_or(&self.default)
+    }
+
+    /// Whether `position` holds a value of its own.
+    pub fn contains(&self, position: Vec2D) -> bool {
+        self.cells.contains_key(&position)
+    }
+
+    /// Stores `value` at `position`, returning what was stored there before.
+    pub fn insert(&mut self, position: Vec2D, value: T) -> Option<T> {
+        self.bounds = Some(grow(self.bounds, position));
+        self.cells.insert(position, value)
+    }
+
+    /// Resets `position` to the default, returning the value it held.
+    pub fn remove(&mut self, position: Vec2D) -> Option<T> {
+        let value = self.cells.remove(&position)?;
+        if let Some((min, max)) = self.bounds {
+            if position.x == min.x
+                || position.y == min.y
+                || position.x == max.x
+                || position.y == max.y
+            {
+                self.bounds = self
+                    .cells
+                    .keys()
+                    .fold(None, |bounds, &p| Some(grow(bounds, p)));
+            }
+        }
+        Some(value)
+    }
+
+    /// Mutable access to `position`, setting it to the default first if it was never set.
+    pub fn entry(&mut self, position: Vec2D) -> &mut T
+    where
+        T: Clone,
+    {
+        if !self.contains(position) {
+            self.insert(position, self.default.clone());
+        }
+        self.cells.get_mut(&position).unwrap()
+    }
+
+    /// Smallest and largest corner of the box around every populated cell, both inclusive.
+    pub fn bounds(&self) -> Option<(Vec2D, Vec2D)> {
+        self.bounds
+    }
+
+    pub fn width(&self) -> usize {
+        self.bounds
+            .map_or(0, |(min, max)| (max.x - min.x + 1) as usize)
+    }
+
+    pub fn height(&self) -> usize {
+        self.bounds
+            .map_or(0, |(min, max)| (max.y - min.y + 1) as usize)
+    }
+
+    /// Number of populated cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The populated cells, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2D, &T)> {
+        self.cells
+            .iter()
+            .map(|(&position, value)| (position, value))
+    }
+
+    /// Dense copy of the bounding box, with its top-left cell at [`SparseMap::bounds`]' minimum.
+    pub fn to_map(&self) -> Map<T>
+    where
+        T: Clone,
+    {
+        let Some((min, _)) = self.bounds else {
+            return Map::new(0, 0, Vec::new());
+        };
+        let (width, height) = (self.width(), self.height());
+        let mut data = Vec::with_capacity(width * height);
+        for y in 0..height as i64 {
+            for x in 0..width as i64 {
+                data.push(self.get(min + Vec2D { x, y }).clone());
+            }
+        }
+        Map::new(height, width, data)
+    }
+}
+
+impl<T: Default> FromIterator<(Vec2D, T)> for SparseMap<T> {
+    fn from_iter<I: IntoIterator<Item = (Vec2D, T)>>(iter: I) -> SparseMap<T> {
+        let mut sparse = SparseMap::new(T::default());
+        sparse.extend(iter);
+        sparse
+    }
+}
+
+impl<T> Extend<(Vec2D, T)> for SparseMap<T> {
+    fn extend<I: IntoIterator<Item = (Vec2D, T)>>(&mut self, iter: I) {
+        for (position, value) in iter {
+            self.insert(position, value);
+        }
+    }
+}
+
+impl<T: Default + PartialEq> From<Map<T>> for SparseMap<T> {
+    /// Keeps the cells that differ from `T::default()`, with the map's top-left cell at the origin.
+    fn from(map: Map<T>) -> SparseMap<T> {
+        SparseMap::from_map(map, Vec2D { x: 0, y: 0 }, T::default())
+    }
+}
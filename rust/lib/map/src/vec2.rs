@@ -0,0 +1,60 @@
+use crate::vector::vector;
+use std::ops::Neg;
+
+/// A position or offset on the grid, with `y` growing downwards.
+///
+/// Coordinates can be any of `i32`, `i64` or `i128`; [`Vec2D`] is the `i64` flavour the rest of
+/// the crate works in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Vec2<N> {
+    pub x: N,
+    pub y: N,
+}
+
+pub type Vec2D = Vec2<i64>;
+
+impl Vec2D {
+    /// Offsets of the four edge-adjacent cells, clockwise from up.
+    pub const ORTHOGONAL: [Vec2D; 4] = [
+        Vec2D { x: 0, y: -1 },
+        Vec2D { x: 1, y: 0 },
+        Vec2D { x: 0, y: 1 },
+        Vec2D { x: -1, y: 0 },
+    ];
+
+    /// Offsets of all eight surrounding cells, clockwise from up.
+    pub const SURROUNDING: [Vec2D; 8] = [
+        Vec2D { x: 0, y: -1 },
+        Vec2D { x: 1, y: -1 },
+        Vec2D { x: 1, y: 0 },
+        Vec2D { x: 1, y: 1 },
+        Vec2D { x: 0, y: 1 },
+        Vec2D { x: -1, y: 1 },
+        Vec2D { x: -1, y: 0 },
+        Vec2D { x: -1, y: -1 },
+    ];
+}
+
+vector!(
+    Vec2 { x, y },
+    [i32, i64, i128],
+    [i32 => i64, i32 => i128, i64 => i128]
+);
+
+impl<N: Neg<Output = N>> Vec2<N> {
+    /// A quarter turn clockwise on the map, taking up to right.
+    pub fn rotate90(self) -> Vec2<N> {
+        Vec2 {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    /// A quarter turn anticlockwise on the map, taking up to left.
+    pub fn rotate270(self) -> Vec2<N> {
+        Vec2 {
+            x: self.y,
+            y: -self.x,
+        }
+    }
+}
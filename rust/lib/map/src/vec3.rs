@@ -0,0 +1,61 @@
+use crate::vector::vector;
+use std::ops::{Mul, Sub};
+
+/// A position or offset in space, the 3D counterpart of [`Vec2`](crate::Vec2).
+///
+/// Coordinates can be any of `i32`, `i64` or `i128`; [`Vec3D`] is the `i64` flavour.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Vec3<N> {
+    pub x: N,
+    pub y: N,
+    pub z: N,
+}
+
+pub type Vec3D = Vec3<i64>;
+
+impl Vec3D {
+    /// Offsets of the six face-adjacent voxels: along x, then y, then z, negative first.
+    pub const ORTHOGONAL: [Vec3D; 6] = [
+        Vec3D { x: -1, y: 0, z: 0 },
+        Vec3D { x: 1, y: 0, z: 0 },
+        Vec3D { x: 0, y: -1, z: 0 },
+        Vec3D { x: 0, y: 1, z: 0 },
+        Vec3D { x: 0, y: 0, z: -1 },
+        Vec3D { x: 0, y: 0, z: 1 },
+    ];
+
+    /// Offsets of all 26 surrounding voxels, ordered by z, then y, then x.
+    pub const SURROUNDING: [Vec3D; 26] = {
+        let mut offsets = [Vec3D { x: 0, y: 0, z: 0 }; 26];
+        let mut i = 0;
+        let mut n = 0;
+        while n < 27 {
+            if n != 13 {
+                offsets[i] = Vec3D {
+                    x: n % 3 - 1,
+                    y: n / 3 % 3 - 1,
+                    z: n / 9 - 1,
+                };
+                i += 1;
+            }
+            n += 1;
+        }
+        offsets
+    };
+}
+
+vector!(
+    Vec3 { x, y, z },
+    [i32, i64, i128],
+    [i32 => i64, i32 => i128, i64 => i128]
+);
+
+impl<N: Copy + Mul<Output = N> + Sub<Output = N>> Vec3<N> {
+    pub fn cross(self, rhs: Vec3<N>) -> Vec3<N> {
+        Vec3 {
+            x: self.y * rhs.z - self.z * rhs.y,
+            y: self.z * rhs.x - self.x * rhs.z,
+            z: self.x * rhs.y - self.y * rhs.x,
+        }
+    }
+}
@@ -0,0 +1,232 @@
+/// Arithmetic shared by the vector types, written once for any list of components.
+///
+/// `vector!(Vec2 { x, y }, [i32, i64], [i32 => i64])` gives `Vec2` its constructor, the
+/// component-wise operators, tuple conversions and `Display`, then the integer methods for each
+/// coordinate type and widening conversions between them.
+macro_rules! vector {
+    ($name:ident $components:tt, [$($n:ty),*], [$($from:ty => $to:ty),*]) => {
+        vector!(@generic $name $components);
+        $(vector!(@coordinate $name $components $n);)*
+        $(vector!(@widen $name $components $from => $to);)*
+    };
+
+    (@generic $name:ident { $first:ident $(, $c:ident)* }) => {
+        impl<N> $name<N> {
+            pub const fn new($first: N $(, $c: N)*) -> $name<N> {
+                $name { $first $(, $c)* }
+            }
+        }
+
+        impl<N: ::std::ops::Add<Output = N>> ::std::ops::Add for $name<N> {
+            type Output = $name<N>;
+            fn add(self, rhs: $name<N>) -> $name<N> {
+                $name { $first: self.$first + rhs.$first $(, $c: self.$c + rhs.$c)* }
+            }
+        }
+
+        impl<N: ::std::ops::Sub<Output = N>> ::std::ops::Sub for $name<N> {
+            type Output = $name<N>;
+            fn sub(self, rhs: $name<N>) -> $name<N> {
+                $name { $first: self.$first - rhs.$first $(, $c: self.$c - rhs.$c)* }
+            }
+        }
+
+        impl<N: ::std::ops::Neg<Output = N>> ::std::ops::Neg for $name<N> {
+            type Output = $name<N>;
+            fn neg(self) -> $name<N> {
+                $name { $first: -self.$first $(, $c: -self.$c)* }
+            }
+        }
+
+        impl<N: ::std::ops::AddAssign> ::std::ops::AddAssign for $name<N> {
+            fn add_assign(&mut self, rhs: $name<N>) {
+                self.$first += rhs.$first;
+                $(self.$c += rhs.$c;)*
+            }
+        }
+
+        impl<N: ::std::ops::SubAssign> ::std::ops::SubAssign for $name<N> {
+            fn sub_assign(&mut self, rhs: $name<N>) {
+                self.$first -= rhs.$first;
+                $(self.$c -= rhs.$c;)*
+            }
+        }
+
+        impl<N> From<(N $(, vector!(@n $c))*)> for $name<N> {
+            fn from(($first $(, $c)*): (N $(, vector!(@n $c))*)) -> $name<N> {
+                $name { $first $(, $c)* }
+            }
+        }
+
+        impl<N> From<$name<N>> for (N $(, vector!(@n $c))*) {
+            fn from(v: $name<N>) -> (N $(, vector!(@n $c))*) {
+                (v.$first $(, v.$c)*)
+            }
+        }
+
+        impl<N: ::std::fmt::Display> ::std::fmt::Display for $name<N> {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                write!(f, "({}", self.$first)?;
+                $(write!(f, ", {}", self.$c)?;)*
+                write!(f, ")")
+            }
+        }
+    };
+
+    (@coordinate $name:ident { $first:ident $(, $c:ident)* } $n:ty) => {
+        impl $name<$n> {
+            pub const ZERO: $name<$n> = $name { $first: 0 $(, $c: 0)* };
+
+            /// Taxicab length, the sum of the absolute components: the number of orthogonal
+            /// steps to cover the offset.
+            pub fn manhattan(self) -> $n {
+                self.$first.abs() $(+ self.$c.abs())*
+            }
+
+            /// Chessboard length, the largest absolute component: the number of moves to cover
+            /// the offset when diagonals are allowed.
+            pub fn chebyshev(self) -> $n {
+                self.$first.abs() $(.max(self.$c.abs()))*
+            }
+
+            pub fn abs(self) -> $name<$n> {
+                $name { $first: self.$first.abs() $(, $c: self.$c.abs())* }
+            }
+
+            /// Each component replaced by -1, 0 or 1, e.g. to step one cell towards a target.
+            pub fn signum(self) -> $name<$n> {
+                $name { $first: self.$first.signum() $(, $c: self.$c.signum())* }
+            }
+
+            /// Component-wise minimum.
+            pub fn min(self, other: $name<$n>) -> $name<$n> {
+                $name { $first: self.$first.min(other.$first) $(, $c: self.$c.min(other.$c))* }
+            }
+
+            /// Component-wise maximum.
+            pub fn max(self, other: $name<$n>) -> $name<$n> {
+                $name { $first: self.$first.max(other.$first) $(, $c: self.$c.max(other.$c))* }
+            }
+
+            pub fn checked_add(self, rhs: $name<$n>) -> Option<$name<$n>> {
+                Some($name {
+                    $first: self.$first.checked_add(rhs.$first)?
+                    $(, $c: self.$c.checked_add(rhs.$c)?)*
+                })
+            }
+
+            pub fn checked_sub(self, rhs: $name<$n>) -> Option<$name<$n>> {
+                Some($name {
+                    $first: self.$first.checked_sub(rhs.$first)?
+                    $(, $c: self.$c.checked_sub(rhs.$c)?)*
+                })
+            }
+
+            pub fn checked_mul(self, rhs: $n) -> Option<$name<$n>> {
+                Some($name {
+                    $first: self.$first.checked_mul(rhs)?
+                    $(, $c: self.$c.checked_mul(rhs)?)*
+                })
+            }
+
+            /// Wrapped sum, and whether any component overflowed.
+            pub fn overflowing_add(self, rhs: $name<$n>) -> ($name<$n>, bool) {
+                let mut overflow = false;
+                let mut component = |(value, overflowed): ($n, bool)| {
+                    overflow |= overflowed;
+                    value
+                };
+                let sum = $name {
+                    $first: component(self.$first.overflowing_add(rhs.$first))
+                    $(, $c: component(self.$c.overflowing_add(rhs.$c)))*
+                };
+                (sum, overflow)
+            }
+
+            pub fn overflowing_sub(self, rhs: $name<$n>) -> ($name<$n>, bool) {
+                let mut overflow = false;
+                let mut component = |(value, overflowed): ($n, bool)| {
+                    overflow |= overflowed;
+                    value
+                };
+                let difference = $name {
+                    $first: component(self.$first.overflowing_sub(rhs.$first))
+                    $(, $c: component(self.$c.overflowing_sub(rhs.$c)))*
+                };
+                (difference, overflow)
+            }
+
+            pub fn overflowing_mul(self, rhs: $n) -> ($name<$n>, bool) {
+                let mut overflow = false;
+                let mut component = |(value, overflowed): ($n, bool)| {
+                    overflow |= overflowed;
+                    value
+                };
+                let product = $name {
+                    $first: component(self.$first.overflowing_mul(rhs))
+                    $(, $c: component(self.$c.overflowing_mul(rhs)))*
+                };
+                (product, overflow)
+            }
+        }
+
+        impl ::std::ops::Mul<$n> for $name<$n> {
+            type Output = $name<$n>;
+            fn mul(self, rhs: $n) -> $name<$n> {
+                $name { $first: self.$first * rhs $(, $c: self.$c * rhs)* }
+            }
+        }
+
+        /// Dot product.
+        impl ::std::ops::Mul<$name<$n>> for $name<$n> {
+            type Output = $n;
+            fn mul(self, rhs: $name<$n>) -> $n {
+                self.$first * rhs.$first $(+ self.$c * rhs.$c)*
+            }
+        }
+
+        impl ::std::ops::MulAssign<$n> for $name<$n> {
+            fn mul_assign(&mut self, rhs: $n) {
+                self.$first *= rhs;
+                $(self.$c *= rhs;)*
+            }
+        }
+
+        /// Component-wise division, rounding towards zero like the integers themselves.
+        impl ::std::ops::Div<$n> for $name<$n> {
+            type Output = $name<$n>;
+            fn div(self, rhs: $n) -> $name<$n> {
+                $name { $first: self.$first / rhs $(, $c: self.$c / rhs)* }
+            }
+        }
+
+        impl ::std::ops::DivAssign<$n> for $name<$n> {
+            fn div_assign(&mut self, rhs: $n) {
+                self.$first /= rhs;
+                $(self.$c /= rhs;)*
+            }
+        }
+    };
+
+    (@widen $name:ident { $($c:ident),+ } $from:ty => $to:ty) => {
+        impl From<$name<$from>> for $name<$to> {
+            fn from(v: $name<$from>) -> $name<$to> {
+                $name { $($c: v.$c.into()),+ }
+            }
+        }
+
+        impl TryFrom<$name<$to>> for $name<$from> {
+            type Error = ::std::num::TryFromIntError;
+            fn try_from(v: $name<$to>) -> Result<$name<$from>, ::std::num::TryFromIntError> {
+                Ok($name { $($c: v.$c.try_into()?),+ })
+            }
+        }
+    };
+
+    // the coordinate type, once per component after the first
+    (@n $c:ident) => {
+        N
+    };
+}
+
+pub(crate) use vector;
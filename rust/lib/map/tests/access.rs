@@ -0,0 +1,61 @@
+use map2d::{Map, OutOfBounds, Vec2D};
+
+fn wide() -> Map<u32> {
+    Map::parse_with("123\n456", |c| c.to_digit(10).ok_or("expected a digit")).unwrap()
+}
+
+const PAST_RIGHT_EDGE: Vec2D = Vec2D { x: 3, y: 0 };
+const BELOW: Vec2D = Vec2D { x: 0, y: 2 };
+const NEGATIVE: Vec2D = Vec2D { x: -1, y: 1 };
+
+#[test]
+fn index_of_round_trips_with_coords() {
+    let map = wide();
+    for index in 0..6 {
+        assert_eq!(map.index_of(map.coords(index)), Some(index));
+    }
+    for outside in [PAST_RIGHT_EDGE, BELOW, NEGATIVE] {
+        assert!(!map.contains(outside));
+        assert_eq!(map.index_of(outside), None);
+        assert_eq!(map.get(outside), None);
+    }
+}
+
+#[test]
+fn out_of_bounds_writes_are_reported() {
+    let mut map = wide();
+    let untouched = map.clone();
+    for outside in [PAST_RIGHT_EDGE, BELOW, NEGATIVE] {
+        assert_eq!(
+            map.try_set(outside, 0),
+            Err(OutOfBounds {
+                position: outside,
+                width: 3,
+                height: 2,
+            })
+        );
+        assert!(map.try_modify(outside, |v| *v += 1).is_err());
+    }
+    assert_eq!(map, untouched);
+
+    map.try_set(Vec2D { x: 2, y: 0 }, 9).unwrap();
+    map.try_modify(Vec2D { x: 0, y: 1 }, |v| *v *= 10).unwrap();
+    assert_eq!(map.row(0), [1, 2, 9]);
+    assert_eq!(map.row(1), [40, 5, 6]);
+}
+
+#[test]
+fn indexing_by_position() {
+    let mut map = wide();
+    assert_eq!(map[Vec2D { x: 1, y: 1 }], 5);
+    map[Vec2D { x: 1, y: 1 }] = 50;
+    map.set(Vec2D { x: 0, y: 0 }, 10);
+    map.modify(Vec2D { x: 2, y: 1 }, |v| *v += 1);
+    assert_eq!(map.into_vec(), [10, 2, 3, 4, 50, 7]);
+}
+
+#[test]
+#[should_panic(expected = "position (3, 0) is outside the 3x2 map")]
+fn set_past_the_right_edge_panics() {
+    wide().set(PAST_RIGHT_EDGE, 0);
+}
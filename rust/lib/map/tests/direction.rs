@@ -0,0 +1,70 @@
+use map2d::{Compass, Direction, Turn, Vec2D};
+
+#[test]
+fn turns_rotate_clockwise_and_back() {
+    for direction in Direction::iter() {
+        assert_eq!(direction.turn_left().turn_right(), direction);
+        assert_eq!(direction.reverse().reverse(), direction);
+        assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+        for turn in [Turn::Straight, Turn::Right, Turn::Back, Turn::Left] {
+            assert_eq!(direction.turn_to(direction.turn(turn)), turn);
+        }
+    }
+    assert_eq!(Direction::North.turn_right(), Direction::East);
+    assert_eq!(Direction::North.turn_left(), Direction::West);
+}
+
+#[test]
+fn perpendicular_matches_dot_product() {
+    for a in Direction::iter() {
+        for b in Direction::iter() {
+            assert_eq!(a.is_perpendicular(b), a.to_vec2d() * b.to_vec2d() == 0);
+        }
+    }
+}
+
+#[test]
+fn steps_convert_to_and_from_vectors() {
+    assert_eq!(Direction::North.to_vec2d(), Vec2D { x: 0, y: -1 });
+    assert_eq!(Vec2D::from(Direction::East), Vec2D { x: 1, y: 0 });
+    for direction in Direction::iter() {
+        assert_eq!(Direction::from_vec2d(direction.to_vec2d()), Some(direction));
+    }
+    assert_eq!(Direction::from_vec2d(Vec2D { x: 1, y: 1 }), None);
+}
+
+#[test]
+fn parses_letters_and_digit_codes() {
+    let parse = |s: &str| s.parse::<Direction>();
+    assert_eq!(parse("U"), Ok(Direction::North));
+    assert_eq!(parse("S"), Ok(Direction::South));
+    assert_eq!(parse("L"), Ok(Direction::West));
+    assert_eq!(parse("0"), Ok(Direction::East));
+    assert_eq!(parse("3"), Ok(Direction::North));
+    assert!(parse("X").is_err());
+    assert!(parse("UD").is_err());
+    assert!(parse("").is_err());
+}
+
+#[test]
+fn notations_parse_on_their_own() {
+    assert_eq!(Direction::from_letter('R'), Ok(Direction::East));
+    assert_eq!(Direction::from_compass('W'), Ok(Direction::West));
+    assert_eq!(Direction::from_digit('1'), Ok(Direction::South));
+    assert!(Direction::from_letter('N').is_err());
+    assert!(Direction::from_letter('0').is_err());
+    assert!(Direction::from_compass('U').is_err());
+    assert!(Direction::from_digit('R').is_err());
+    assert!(Direction::from_digit('4').is_err());
+}
+
+#[test]
+fn compass_covers_diagonals() {
+    assert_eq!(Compass::iter().count(), 8);
+    assert_eq!(Compass::North.turn_right(), Compass::NorthEast);
+    assert_eq!(Compass::North.turn_left(), Compass::NorthWest);
+    assert_eq!(Compass::SouthEast.reverse(), Compass::NorthWest);
+    assert_eq!(Compass::SouthEast.to_vec2d(), Vec2D { x: 1, y: 1 });
+    assert_eq!(Compass::from(Direction::West), Compass::West);
+    assert_eq!(Compass::iter().filter(|c| c.is_diagonal()).count(), 4);
+}
@@ -0,0 +1,76 @@
+use map2d::{Map, Vec2D};
+
+fn rooms() -> Map<char> {
+    "#######\n\
+     #..#..#\n\
+     #..#...\n\
+     ####.##\n\
+     #..#..#"
+        .parse()
+        .unwrap()
+}
+
+fn open(c: &char) -> bool {
+    *c == '.'
+}
+
+#[test]
+fn flood_fill_stays_behind_walls() {
+    let map = rooms();
+    let room = map.flood_fill(Vec2D { x: 1, y: 1 }, open);
+    assert_eq!(room.len(), 4);
+    assert_eq!(room[0], Vec2D { x: 1, y: 1 });
+
+    let corridor = map.flood_fill(Vec2D { x: 5, y: 1 }, open);
+    assert_eq!(corridor.len(), 8);
+    assert!(corridor.contains(&Vec2D { x: 6, y: 2 }));
+    assert!(corridor.contains(&Vec2D { x: 5, y: 4 }));
+
+    assert!(map.flood_fill(Vec2D { x: 0, y: 0 }, open).is_empty());
+    assert!(map.flood_fill(Vec2D { x: -1, y: 0 }, open).is_empty());
+}
+
+#[test]
+fn outside_fill_leaves_enclosed_cells() {
+    let map = rooms();
+    let outside = map.fill_outside(open);
+    // only the top-left room is walled in, every other open cell reaches the edge
+    let mut cells: Vec<(i64, i64)> = outside.iter().map(|p| (p.x, p.y)).collect();
+    cells.sort();
+    assert_eq!(
+        cells,
+        [
+            (1, 4),
+            (2, 4),
+            (4, 1),
+            (4, 2),
+            (4, 3),
+            (4, 4),
+            (5, 1),
+            (5, 2),
+            (5, 4),
+            (6, 2)
+        ]
+    );
+    let enclosed = map.rows().flatten().filter(|&&c| c == '.').count() - outside.len();
+    assert_eq!(enclosed, 4);
+}
+
+#[test]
+fn components_label_equal_regions() {
+    let map: Map<char> = "aab\nabb\nccb".parse().unwrap();
+    let components = map.components(|a, b| a == b);
+    assert_eq!(components.sizes, [3, 4, 2]);
+    assert_eq!(components.labels.row(0), [0, 0, 1]);
+    assert_eq!(components.labels.row(2), [2, 2, 1]);
+    assert_eq!(components.sizes.iter().sum::<usize>(), 9);
+}
+
+#[test]
+fn components_can_split_equal_cells() {
+    let map: Map<u8> = Map::new(1, 5, vec![1, 2, 3, 5, 6]);
+    // neighbours join only when their values differ by one
+    let components = map.components(|a, b| a.abs_diff(*b) == 1);
+    assert_eq!(components.sizes, [3, 2]);
+    assert_eq!(components.labels.into_vec(), [0, 0, 0, 1, 1]);
+}
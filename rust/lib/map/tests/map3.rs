@@ -0,0 +1,114 @@
+use map2d::{Map3, OutOfBounds3, Vec3, Vec3D};
+use std::collections::HashSet;
+
+/// Two layers of two rows of three, numbered in storage order.
+fn block() -> Map3<u32> {
+    Map3::new(2, 2, 3, (0..12).collect())
+}
+
+#[test]
+fn vec3_arithmetic_matches_vec2() {
+    let mut v = Vec3D::new(1, -2, 3);
+    assert_eq!(-v, Vec3D::new(-1, 2, -3));
+    assert_eq!(v * 2 / 2, v);
+    assert_eq!(v * Vec3D::new(1, 1, 1), 2);
+    assert_eq!(v.manhattan(), 6);
+    assert_eq!(v.chebyshev(), 3);
+    assert_eq!(v.signum(), Vec3D::new(1, -1, 1));
+    assert_eq!(v.min(Vec3D::ZERO), Vec3D::new(0, -2, 0));
+    assert_eq!(v.max(Vec3D::ZERO), Vec3D::new(1, 0, 3));
+    v += Vec3D::new(1, 1, 1);
+    v -= Vec3D::new(0, 0, 4);
+    assert_eq!(v, (2, -1, 0).into());
+    assert_eq!(
+        Vec3D::new(1, 0, 0).cross(Vec3D::new(0, 1, 0)),
+        Vec3D::new(0, 0, 1)
+    );
+    assert_eq!(
+        Vec3::new(i32::MAX, 0, 0).checked_add(Vec3::new(1, 0, 0)),
+        None
+    );
+    assert!(
+        Vec3::new(0, 0, i32::MIN)
+            .overflowing_sub(Vec3::new(0, 0, 1))
+            .1
+    );
+    assert_eq!(Vec3D::from(Vec3::new(1i32, 2, 3)).to_string(), "(1, 2, 3)");
+}
+
+#[test]
+fn offsets_cover_the_neighbourhood() {
+    assert!(Vec3D::ORTHOGONAL.iter().all(|v| v.manhattan() == 1));
+    assert!(Vec3D::SURROUNDING.iter().all(|v| v.chebyshev() == 1));
+    let distinct: HashSet<Vec3D> = Vec3D::SURROUNDING.into_iter().collect();
+    assert_eq!(distinct.len(), 26);
+}
+
+#[test]
+fn positions_map_to_storage_order() {
+    let map = block();
+    for index in 0..12 {
+        let position = map.coords(index);
+        assert_eq!(map.index_of(position), Some(index));
+        assert_eq!(map[position], index as u32);
+    }
+    assert_eq!(map.coords(7), Vec3D::new(1, 0, 1));
+    assert_eq!(map.get(Vec3D::new(3, 0, 0)), None);
+    assert_eq!(map.get(Vec3D::new(0, 0, 2)), None);
+}
+
+#[test]
+fn writes_are_bounds_checked() {
+    let mut map = block();
+    map.set(Vec3D::new(2, 1, 1), 100);
+    *map.get_mut(Vec3D::new(0, 0, 0)).unwrap() = 50;
+    assert_eq!(map[Vec3D::new(2, 1, 1)], 100);
+    let outside = Vec3D::new(0, 2, 0);
+    assert_eq!(
+        map.try_set(outside, 1),
+        Err(OutOfBounds3 {
+            position: outside,
+            width: 3,
+            height: 2,
+            depth: 2,
+        })
+    );
+    assert_eq!(
+        OutOfBounds3 {
+            position: outside,
+            width: 3,
+            height: 2,
+            depth: 2,
+        }
+        .to_string(),
+        "position (0, 2, 0) is outside the 3x2x2 map"
+    );
+}
+
+#[test]
+fn layers_slice_by_z() {
+    let mut map = block();
+    assert_eq!(map.layer(1), [6, 7, 8, 9, 10, 11]);
+    assert_eq!(map.layers().count(), 2);
+    map.layer_mut(0).fill(0);
+    let bottom = map.layer_map(0);
+    assert_eq!((bottom.height, bottom.width), (2, 3));
+    assert!(bottom.into_vec().iter().all(|&v| v == 0));
+}
+
+#[test]
+fn neighbours_skip_voxels_outside() {
+    let map = Map3::filled(3, 3, 3, '.');
+    let corner = Vec3D::ZERO;
+    let centre = Vec3D::new(1, 1, 1);
+    assert_eq!(map.neighbours6(corner).count(), 3);
+    assert_eq!(map.neighbours26(corner).count(), 7);
+    assert_eq!(map.neighbours6(centre).count(), 6);
+    assert_eq!(map.neighbours26(centre).count(), 26);
+    let below: Vec<Vec3D> = map
+        .neighbours6(centre)
+        .map(|(p, _)| p)
+        .filter(|p| p.z < 1)
+        .collect();
+    assert_eq!(below, [Vec3D::new(1, 1, 0)]);
+}
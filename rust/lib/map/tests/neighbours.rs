@@ -0,0 +1,56 @@
+use map2d::{Map, Vec2D};
+
+fn grid() -> Map<u32> {
+    Map::parse_with("123\n456\n789", |c| {
+        c.to_digit(10).ok_or("expected a digit")
+    })
+    .unwrap()
+}
+
+fn values<'a>(neighbours: impl Iterator<Item = (Vec2D, &'a u32)>) -> Vec<u32> {
+    neighbours.map(|(_, &value)| value).collect()
+}
+
+#[test]
+fn neighbours_skip_cells_outside_the_map() {
+    let map = grid();
+    let corner = Vec2D { x: 0, y: 0 };
+    let centre = Vec2D { x: 1, y: 1 };
+
+    assert_eq!(values(map.neighbours4(corner)), [2, 4]);
+    assert_eq!(values(map.neighbours8(corner)), [2, 5, 4]);
+    assert_eq!(values(map.neighbours4(centre)), [2, 6, 8, 4]);
+    assert_eq!(values(map.neighbours8(centre)), [2, 3, 6, 9, 8, 7, 4, 1]);
+}
+
+#[test]
+fn neighbours_yield_their_positions() {
+    let map = grid();
+    let positions: Vec<Vec2D> = map
+        .neighbours4(Vec2D { x: 2, y: 2 })
+        .map(|(position, _)| position)
+        .collect();
+    assert_eq!(positions, [Vec2D { x: 2, y: 1 }, Vec2D { x: 1, y: 2 }]);
+}
+
+#[test]
+fn stencil_takes_arbitrary_offsets() {
+    let map = grid();
+    let knight = [
+        Vec2D { x: 1, y: 2 },
+        Vec2D { x: 2, y: 1 },
+        Vec2D { x: -1, y: 2 },
+    ];
+    assert_eq!(values(map.stencil(Vec2D { x: 0, y: 0 }, knight)), [8, 6]);
+}
+
+#[test]
+fn wrapping_neighbours_tile_the_plane() {
+    let map = grid();
+    let corner = Vec2D { x: 0, y: 0 };
+
+    assert_eq!(values(map.neighbours4_wrapping(corner)), [7, 2, 4, 3]);
+    assert_eq!(values(map.neighbours8_wrapping(corner)).len(), 8);
+    assert_eq!(map.wrap(Vec2D { x: -1, y: 7 }), Vec2D { x: 2, y: 1 });
+    assert_eq!(*map.get_wrapping(Vec2D { x: 4, y: -4 }), 8);
+}
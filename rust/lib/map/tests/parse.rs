@@ -0,0 +1,67 @@
+use aoc_common::{ParsingError, Position};
+use map2d::{Map, Vec2D};
+
+#[derive(Debug, PartialEq)]
+enum Tile {
+    Open,
+    Wall,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = &'static str;
+
+    fn try_from(c: char) -> Result<Tile, &'static str> {
+        match c {
+            '.' => Ok(Tile::Open),
+            '#' => Ok(Tile::Wall),
+            _ => Err("expected '.' or '#'"),
+        }
+    }
+}
+
+fn parse_error(input: &str) -> ParsingError {
+    match input.parse::<Map<Tile>>() {
+        Ok(_) => panic!("expected {:?} to be rejected", input),
+        Err(e) => e,
+    }
+}
+
+#[test]
+fn from_str_converts_cells_with_try_from() {
+    let map: Map<Tile> = ".#.\n##.\n".parse().unwrap();
+    assert_eq!((map.width, map.height), (3, 2));
+    assert_eq!(map.get(Vec2D { x: 1, y: 0 }), Some(&Tile::Wall));
+    assert_eq!(map.get(Vec2D { x: 2, y: 1 }), Some(&Tile::Open));
+}
+
+#[test]
+fn parse_with_converts_digits() {
+    let map = Map::parse_with("123\n456", |c| c.to_digit(10).ok_or("expected a digit")).unwrap();
+    assert_eq!(map.into_vec(), [1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn invalid_cell_is_located() {
+    let error = parse_error("..\n.x\n");
+    match error {
+        ParsingError::InvalidCell { position, cell, .. } => {
+            assert_eq!(position, Position { line: 2, column: 2 });
+            assert_eq!(cell, 'x');
+        }
+        other => panic!("expected InvalidCell, got {:?}", other),
+    }
+}
+
+#[test]
+fn first_ragged_row_is_reported() {
+    let error = parse_error("...\n...\n..\n....\n");
+    assert_eq!(
+        error.to_string(),
+        "line 3: grid row has 2 cells, expected 3"
+    );
+}
+
+#[test]
+fn empty_input_is_missing_grid() {
+    assert!(matches!(parse_error(""), ParsingError::Missing("grid")));
+}
@@ -0,0 +1,134 @@
+use map2d::{Direction, Orientation, Polygon, Vec2D};
+
+fn polygon(points: &[(i64, i64)]) -> Polygon {
+    Polygon::new(points.iter().map(|&(x, y)| Vec2D { x, y }).collect())
+}
+
+/// Lattice points inside or on `polygon`, counted one by one.
+fn brute_force(polygon: &Polygon, size: i64) -> i128 {
+    let on_edge = |p: Vec2D, a: Vec2D, b: Vec2D| {
+        (b - a).x * (p - a).y == (b - a).y * (p - a).x
+            && (p.x - a.x) * (p.x - b.x) <= 0
+            && (p.y - a.y) * (p.y - b.y) <= 0
+    };
+    let vertices = polygon.vertices();
+    let edges: Vec<(Vec2D, Vec2D)> = (0..vertices.len())
+        .map(|i| (vertices[i], vertices[(i + 1) % vertices.len()]))
+        .collect();
+    let mut count = 0;
+    for y in 0..=size {
+        for x in 0..=size {
+            let p = Vec2D { x, y };
+            if edges.iter().any(|&(a, b)| on_edge(p, a, b)) {
+                count += 1;
+                continue;
+            }
+            // even-odd ray cast towards +x, edges half-open in y so vertices count once
+            let crossings = edges
+                .iter()
+                .filter(|&&(a, b)| {
+                    (a.y > p.y) != (b.y > p.y) && {
+                        let t = (p.y - a.y) as f64 / (b.y - a.y) as f64;
+                        a.x as f64 + t * (b.x - a.x) as f64 > p.x as f64
+                    }
+                })
+                .count();
+            count += crossings as i128 % 2;
+        }
+    }
+    count
+}
+
+#[test]
+fn square_measures() {
+    let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+    assert_eq!(square.double_signed_area(), 32);
+    assert_eq!(square.area(), 16);
+    assert_eq!(square.perimeter(), 16.0);
+    assert_eq!(square.boundary_points(), 16);
+    assert_eq!(square.interior_points(), 9);
+    assert_eq!(square.lattice_points(), 25);
+    assert_eq!(square.lattice_points(), brute_force(&square, 4));
+}
+
+#[test]
+fn orientation_follows_the_winding() {
+    let clockwise = polygon(&[(0, 0), (3, 0), (3, 2), (0, 2)]);
+    let mut reversed = clockwise.vertices().to_vec();
+    reversed.reverse();
+    let counter_clockwise = Polygon::new(reversed);
+
+    assert_eq!(clockwise.orientation(), Some(Orientation::Clockwise));
+    assert_eq!(
+        counter_clockwise.orientation(),
+        Some(Orientation::CounterClockwise)
+    );
+    assert_eq!(
+        clockwise.double_signed_area(),
+        -counter_clockwise.double_signed_area()
+    );
+    assert_eq!(clockwise.area(), counter_clockwise.area());
+    assert_eq!(
+        clockwise.lattice_points(),
+        counter_clockwise.lattice_points()
+    );
+    assert_eq!(polygon(&[(0, 0), (5, 0)]).orientation(), None);
+}
+
+#[test]
+fn diagonal_edges_use_exact_lattice_counts() {
+    let triangle = polygon(&[(0, 0), (4, 0), (0, 3)]);
+    assert_eq!(triangle.double_signed_area(), 12);
+    assert_eq!(triangle.perimeter(), 12.0);
+    // the hypotenuse passes through no lattice points besides its ends
+    assert_eq!(triangle.boundary_points(), 8);
+    assert_eq!(triangle.interior_points(), 3);
+    assert_eq!(triangle.lattice_points(), brute_force(&triangle, 4));
+}
+
+#[test]
+fn self_touching_lobes_share_their_corner() {
+    // two squares meeting at (2, 2), walked as a single figure eight
+    let eight = polygon(&[
+        (0, 0),
+        (2, 0),
+        (2, 2),
+        (4, 2),
+        (4, 4),
+        (2, 4),
+        (2, 2),
+        (0, 2),
+    ]);
+    assert_eq!(eight.area(), 8);
+    assert_eq!(eight.boundary_points(), 15);
+    assert_eq!(eight.interior_points(), 2);
+    assert_eq!(eight.lattice_points(), 17);
+    assert_eq!(eight.lattice_points(), brute_force(&eight, 4));
+}
+
+#[test]
+fn traces_dig_plans_far_from_the_origin() {
+    let steps = [
+        (Direction::East, 3_000_000_000),
+        (Direction::South, 2_000_000_000),
+        (Direction::West, 3_000_000_000),
+        (Direction::North, 2_000_000_000),
+    ];
+    let plan = Polygon::trace(
+        Vec2D { x: 0, y: 0 },
+        steps.iter().map(|&(d, n)| d.to_vec2d() * n),
+    )
+    .unwrap();
+    assert_eq!(plan.vertices().len(), 4);
+    assert_eq!(plan.area(), 6_000_000_000_000_000_000);
+    assert_eq!(plan.lattice_points(), 3_000_000_001 * 2_000_000_001);
+}
+
+#[test]
+fn trace_rejects_open_walks() {
+    let steps = [
+        Direction::East.to_vec2d() * 6,
+        Direction::South.to_vec2d() * 5,
+    ];
+    assert_eq!(Polygon::trace(Vec2D { x: 0, y: 0 }, steps), None);
+}
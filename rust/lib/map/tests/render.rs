@@ -0,0 +1,66 @@
+use map2d::render::{Overlay, Renderer, Rgb};
+use map2d::{Map, Vec2D};
+
+fn grid() -> Map<char> {
+    ".#.\n#..".parse().unwrap()
+}
+
+fn render(write: impl Fn(&mut Vec<u8>) -> std::io::Result<()>) -> Vec<u8> {
+    let mut out = Vec::new();
+    write(&mut out).unwrap();
+    out
+}
+
+#[test]
+fn ascii_applies_overlay_glyphs_in_order() {
+    let map = grid();
+    let path = [Vec2D { x: 0, y: 0 }, Vec2D { x: 1, y: 0 }];
+    let renderer = Renderer::new(&map, |&c| c)
+        .with_overlay(Overlay::new(path, Rgb::RED).with_glyph('o'))
+        .with_overlay(Overlay::new([Vec2D { x: 1, y: 0 }], Rgb::GREEN).with_glyph('X'))
+        .with_overlay(Overlay::new([Vec2D { x: 2, y: 1 }], Rgb::BLUE));
+    let text = render(|out| renderer.write_ascii(out));
+    assert_eq!(String::from_utf8(text).unwrap(), "oX.\n#..\n");
+}
+
+#[test]
+fn ansi_switches_colour_only_when_it_changes() {
+    let map = grid();
+    let renderer = Renderer::new(&map, |&c| c);
+    let text = String::from_utf8(render(|out| renderer.write_ansi(out))).unwrap();
+    let black = "\x1b[38;2;0;0;0m";
+    let white = "\x1b[38;2;255;255;255m";
+    assert_eq!(
+        text,
+        format!("{black}.{white}#{black}.\x1b[0m\n{white}#{black}..\x1b[0m\n")
+    );
+}
+
+#[test]
+fn ppm_scales_cells_into_pixel_blocks() {
+    let map: Map<u8> = Map::new(1, 2, vec![0, 200]);
+    let renderer = Renderer::new(&map, |_| ' ')
+        .with_colour(|&level| Rgb::grey(level))
+        .with_scale(2);
+    let image = render(|out| renderer.write_ppm(out));
+    let header = b"P6\n4 2\n255\n";
+    assert_eq!(&image[..header.len()], header);
+    let row = [0, 0, 0, 0, 0, 0, 200, 200, 200, 200, 200, 200];
+    assert_eq!(&image[header.len()..], [row, row].concat());
+}
+
+#[test]
+fn png_decodes_to_the_same_pixels() {
+    let map = grid();
+    let renderer =
+        Renderer::new(&map, |&c| c).with_overlay(Overlay::new([Vec2D { x: 2, y: 0 }], Rgb::RED));
+    let image = render(|out| renderer.write_png(out));
+
+    let mut reader = png::Decoder::new(image.as_slice()).read_info().unwrap();
+    let mut pixels = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut pixels).unwrap();
+    assert_eq!((info.width, info.height), (3, 2));
+    let ppm = render(|out| renderer.write_ppm(out));
+    assert_eq!(pixels, ppm[b"P6\n3 2\n255\n".len()..]);
+    assert_eq!(pixels[6..9], [230, 40, 40]);
+}
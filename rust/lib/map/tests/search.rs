@@ -0,0 +1,94 @@
+use map2d::search::{astar, bfs, dijkstra};
+use map2d::{Map, Vec2D};
+
+fn maze() -> Map<char> {
+    Map::parse_with(
+        "S.#...\n\
+         .##.#.\n\
+         ...#..\n\
+         .#...T",
+        Ok::<char, &str>,
+    )
+    .unwrap()
+}
+
+fn open_neighbours(map: &Map<char>, position: Vec2D) -> Vec<Vec2D> {
+    map.neighbours4(position)
+        .filter(|(_, &c)| c != '#')
+        .map(|(p, _)| p)
+        .collect()
+}
+
+#[test]
+fn bfs_finds_a_shortest_path() {
+    let map = maze();
+    let target = Vec2D { x: 5, y: 3 };
+    let (steps, path) = bfs(
+        Vec2D { x: 0, y: 0 },
+        |&p| open_neighbours(&map, p),
+        |&p| p == target,
+    )
+    .unwrap();
+
+    assert_eq!(steps, 8);
+    assert_eq!(path.len(), steps + 1);
+    assert_eq!(path.first(), Some(&Vec2D { x: 0, y: 0 }));
+    assert_eq!(path.last(), Some(&target));
+    assert!(path.windows(2).all(|w| (w[1] - w[0]) * (w[1] - w[0]) == 1));
+}
+
+#[test]
+fn unreachable_goal_is_none() {
+    let map = maze();
+    let walled_in = |p: &Vec2D| *p == Vec2D { x: 100, y: 100 };
+    assert!(bfs(
+        Vec2D { x: 0, y: 0 },
+        |&p| open_neighbours(&map, p),
+        walled_in
+    )
+    .is_none());
+    assert!(dijkstra(
+        Vec2D { x: 0, y: 0 },
+        |&p| open_neighbours(&map, p).into_iter().map(|n| (n, 1u32)),
+        walled_in,
+    )
+    .is_none());
+}
+
+#[test]
+fn dijkstra_prefers_cheap_detours() {
+    // 0 -> 3 directly costs 10, through 1 and 2 it costs 6.
+    let edges = |&n: &u32| -> Vec<(u32, u64)> {
+        match n {
+            0 => vec![(3, 10), (1, 2)],
+            1 => vec![(2, 2)],
+            2 => vec![(3, 2)],
+            _ => vec![],
+        }
+    };
+    assert_eq!(dijkstra(0, edges, |&n| n == 3), Some((6, vec![0, 1, 2, 3])));
+}
+
+#[test]
+fn astar_agrees_with_dijkstra() {
+    let map = maze();
+    let target = Vec2D { x: 5, y: 3 };
+    let successors = |&p: &Vec2D| {
+        open_neighbours(&map, p)
+            .into_iter()
+            .map(|n| (n, 1i64))
+            .collect::<Vec<_>>()
+    };
+    let manhattan = |p: &Vec2D| (target.x - p.x).abs() + (target.y - p.y).abs();
+
+    let (cost, path) = astar(
+        Vec2D { x: 0, y: 0 },
+        successors,
+        |&p| p == target,
+        manhattan,
+    )
+    .unwrap();
+    let (expected, _) = dijkstra(Vec2D { x: 0, y: 0 }, successors, |&p| p == target).unwrap();
+    assert_eq!(cost, expected);
+    assert_eq!(path.len() as i64, cost + 1);
+}
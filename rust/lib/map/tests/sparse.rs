@@ -0,0 +1,67 @@
+use map2d::{Direction, Map, SparseMap, Vec2D};
+
+fn text(map: &Map<char>) -> String {
+    map.rows()
+        .map(|row| row.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn unset_cells_read_as_the_default() {
+    let mut sparse = SparseMap::new('.');
+    assert!(sparse.is_empty());
+    assert_eq!(sparse.bounds(), None);
+    assert_eq!(*sparse.get(Vec2D { x: -5, y: 1000 }), '.');
+
+    assert_eq!(sparse.insert(Vec2D { x: -5, y: 1000 }, '#'), None);
+    assert_eq!(sparse.insert(Vec2D { x: -5, y: 1000 }, '@'), Some('#'));
+    assert_eq!(*sparse.get(Vec2D { x: -5, y: 1000 }), '@');
+    assert!(sparse.contains(Vec2D { x: -5, y: 1000 }));
+    assert_eq!(sparse.len(), 1);
+}
+
+#[test]
+fn bounds_follow_inserts_and_removals() {
+    let mut sparse = SparseMap::new(0u8);
+    let mut position = Vec2D { x: 0, y: 0 };
+    for direction in [Direction::West, Direction::West, Direction::North] {
+        position += direction.to_vec2d();
+        *sparse.entry(position) += 1;
+    }
+    assert_eq!(
+        sparse.bounds(),
+        Some((Vec2D { x: -2, y: -1 }, Vec2D { x: -1, y: 0 }))
+    );
+    assert_eq!((sparse.width(), sparse.height()), (2, 2));
+
+    assert_eq!(sparse.remove(Vec2D { x: -2, y: -1 }), Some(1));
+    assert_eq!(sparse.remove(Vec2D { x: -2, y: -1 }), None);
+    assert_eq!(
+        sparse.bounds(),
+        Some((Vec2D { x: -2, y: 0 }, Vec2D { x: -1, y: 0 }))
+    );
+    let mut cells: Vec<_> = sparse.iter().map(|(p, &v)| (p.x, p.y, v)).collect();
+    cells.sort();
+    assert_eq!(cells, [(-2, 0, 1), (-1, 0, 1)]);
+}
+
+#[test]
+fn converts_to_and_from_dense_maps() {
+    let dense: Map<char> = "#..\n.#.\n..#".parse().unwrap();
+    let sparse = SparseMap::from_map(dense.clone(), Vec2D { x: -1, y: -1 }, '.');
+    assert_eq!(sparse.len(), 3);
+    assert_eq!(*sparse.get(Vec2D { x: 0, y: 0 }), '#');
+    assert_eq!(sparse.to_map(), dense);
+
+    let trimmed = SparseMap::from_map("...\n.#.\n...".parse().unwrap(), Vec2D { x: 0, y: 0 }, '.');
+    assert_eq!(text(&trimmed.to_map()), "#");
+    assert_eq!(SparseMap::new('.').to_map().into_vec(), []);
+}
+
+#[test]
+fn collects_from_positions() {
+    let diagonal: SparseMap<u32> = (0..4).map(|i| (Vec2D { x: i, y: i }, 1)).collect();
+    assert_eq!(diagonal.to_map().into_vec().iter().sum::<u32>(), 4);
+    assert_eq!(SparseMap::from(diagonal.to_map()), diagonal);
+}
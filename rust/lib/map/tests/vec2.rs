@@ -0,0 +1,71 @@
+use map2d::{Direction, Vec2, Vec2D};
+
+#[test]
+fn arithmetic_and_compound_assignment() {
+    let mut v = Vec2D::new(3, -4);
+    assert_eq!(-v, Vec2D { x: -3, y: 4 });
+    assert_eq!(v / 2, Vec2D { x: 1, y: -2 });
+    assert_eq!(v * Vec2D::new(2, 1), 2);
+    v += Vec2D::new(1, 1);
+    assert_eq!(v, Vec2D::new(4, -3));
+    v -= Vec2D::new(4, 0);
+    v *= 3;
+    assert_eq!(v, Vec2D::new(0, -9));
+    v /= -3;
+    assert_eq!(v, Vec2D::new(0, 3));
+}
+
+#[test]
+fn norms_and_component_helpers() {
+    let v = Vec2D::new(3, -4);
+    assert_eq!(v.manhattan(), 7);
+    assert_eq!(v.chebyshev(), 4);
+    assert_eq!(v.abs(), Vec2D::new(3, 4));
+    assert_eq!(v.signum(), Vec2D::new(1, -1));
+    assert_eq!(Vec2D::ZERO.signum(), Vec2D::ZERO);
+    assert_eq!(v.min(Vec2D::new(0, 0)), Vec2D::new(0, -4));
+    assert_eq!(v.max(Vec2D::new(0, 0)), Vec2D::new(3, 0));
+}
+
+#[test]
+fn rotations_match_direction_turns() {
+    for direction in Direction::iter() {
+        let step = direction.to_vec2d();
+        assert_eq!(step.rotate90(), direction.turn_right().to_vec2d());
+        assert_eq!(step.rotate270(), direction.turn_left().to_vec2d());
+    }
+    let v = Vec2D::new(2, 5);
+    assert_eq!(v.rotate90().rotate90(), -v);
+    assert_eq!(v.rotate90().rotate270(), v);
+}
+
+#[test]
+fn checked_and_overflowing_ops() {
+    let big = Vec2::new(i32::MAX, 0);
+    assert_eq!(big.checked_add(Vec2::new(1, 0)), None);
+    assert_eq!(
+        big.checked_sub(Vec2::new(1, 0)),
+        Some(Vec2::new(i32::MAX - 1, 0))
+    );
+    assert_eq!(Vec2::new(0, i32::MIN).checked_mul(-1), None);
+    assert_eq!(
+        big.overflowing_add(Vec2::new(1, 1)),
+        (Vec2::new(i32::MIN, 1), true)
+    );
+    assert_eq!(
+        big.overflowing_sub(Vec2::new(0, 1)),
+        (Vec2::new(i32::MAX, -1), false)
+    );
+    assert!(big.overflowing_mul(2).1);
+}
+
+#[test]
+fn conversions_between_widths_and_tuples() {
+    let small: Vec2<i32> = (7, -2).into();
+    let wide: Vec2<i128> = Vec2D::from(small).into();
+    assert_eq!(wide, Vec2::new(7i128, -2));
+    assert!(Vec2::<i32>::try_from(Vec2D::new(1 << 40, 0)).is_err());
+    assert_eq!(Vec2::<i64>::try_from(wide), Ok(Vec2D::new(7, -2)));
+    assert_eq!(<(i64, i64)>::from(Vec2D::new(1, 2)), (1, 2));
+    assert_eq!(Vec2D::new(-1, 20).to_string(), "(-1, 20)");
+}
@@ -0,0 +1,59 @@
+use map2d::{Map, Vec2D};
+
+/// Two rows of three, so that mixing up width and height shows.
+fn wide() -> Map<char> {
+    "abc\ndef".parse().unwrap()
+}
+
+fn text(map: &Map<char>) -> String {
+    map.rows()
+        .map(|row| row.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn columns_and_coords_respect_non_square_maps() {
+    let map = wide();
+    assert_eq!(map.column(1).collect::<String>(), "be");
+    assert_eq!(map.column_iter(2).collect::<String>(), "cf");
+    let columns: Vec<String> = map.columns().map(|c| c.collect()).collect();
+    assert_eq!(columns, ["ad", "be", "cf"]);
+    assert_eq!(map.row(1), ['d', 'e', 'f']);
+    assert_eq!(map.coords(4), Vec2D { x: 1, y: 1 });
+    assert_eq!(map.coords(5), Vec2D { x: 2, y: 1 });
+}
+
+#[test]
+fn rows_and_columns_can_be_edited() {
+    let mut map = wide();
+    map.row_mut(0)[0] = 'A';
+    map.column_mut(2).for_each(|c| *c = c.to_ascii_uppercase());
+    map.rows_mut().last().unwrap()[1] = 'E';
+    assert_eq!(text(&map), "AbC\ndEF");
+}
+
+#[test]
+fn transforms_turn_and_mirror_the_grid() {
+    let map = wide();
+    assert_eq!(text(&map.transpose()), "ad\nbe\ncf");
+    assert_eq!(text(&map.rotate_cw()), "da\neb\nfc");
+    assert_eq!(text(&map.rotate_ccw()), "cf\nbe\nad");
+    assert_eq!(text(&map.flip_h()), "cba\nfed");
+    assert_eq!(text(&map.flip_v()), "def\nabc");
+
+    let rotated = map.rotate_cw();
+    assert_eq!((rotated.height, rotated.width), (3, 2));
+    assert_eq!(rotated.rotate_ccw(), map);
+    assert_eq!(map.rotate_cw().rotate_cw(), map.flip_h().flip_v());
+    assert_eq!(map.transpose().transpose(), map);
+}
+
+#[test]
+fn in_place_flips_handle_odd_heights() {
+    let mut map: Map<char> = "ab\ncd\nef".parse().unwrap();
+    map.flip_v_mut();
+    assert_eq!(text(&map), "ef\ncd\nab");
+    map.flip_h_mut();
+    assert_eq!(text(&map), "fe\ndc\nba");
+}